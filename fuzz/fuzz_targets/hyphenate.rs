@@ -0,0 +1,50 @@
+#![no_main]
+
+use hypher::{hyphenate_bounded, hyphenate_explain, hyphenate_stripped, Lang};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: (u8, u8, u8, &str)| {
+    let (lang, left_min, right_min, word) = input;
+    let lang = Lang::ALL[usize::from(lang) % Lang::ALL.len()];
+    let left_min = usize::from(left_min % 6);
+    let right_min = usize::from(right_min % 6);
+
+    let syllables: Vec<_> = hyphenate_bounded(word, lang, left_min, right_min).collect();
+    assert_eq!(syllables.concat(), word);
+    assert!(syllables.iter().all(|syllable| !syllable.is_empty()));
+
+    // Breaks after soft hyphens ignore the bounds. Otherwise, each bound
+    // spans at least as many chars as grapheme clusters.
+    if !word.contains('\u{ad}') {
+        check_bounds(&syllables, left_min, right_min);
+    } else {
+        check_soft_hyphens(&syllables);
+    }
+
+    let syllables: Vec<_> = hyphenate_stripped(word, lang, left_min, right_min).collect();
+    assert_eq!(syllables.concat(), word);
+
+    let explanation = hyphenate_explain(word, lang);
+    assert_eq!(explanation.levels.len(), explanation.dotted.len() + 1);
+});
+
+/// Check that no break lies within the bounds.
+fn check_bounds(syllables: &[&str], left_min: usize, right_min: usize) {
+    let total: usize = syllables.iter().map(|s| s.chars().count()).sum();
+    let mut before = 0;
+    for syllable in &syllables[..syllables.len().saturating_sub(1)] {
+        before += syllable.chars().count();
+        assert!(before >= left_min.max(1));
+        assert!(total - before >= right_min.max(1));
+    }
+}
+
+/// Check that each break follows a soft hyphen between two letters.
+fn check_soft_hyphens(syllables: &[&str]) {
+    for pair in syllables.windows(2) {
+        assert!(pair[0].ends_with('\u{ad}'));
+        let before = pair[0].trim_end_matches('\u{ad}').chars().last();
+        assert!(before.map_or(false, char::is_alphabetic));
+        assert!(pair[1].chars().next().map_or(false, char::is_alphabetic));
+    }
+}
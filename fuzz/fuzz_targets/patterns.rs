@@ -0,0 +1,23 @@
+#![no_main]
+
+use hypher::{parse_tex, Dic, Trie, TrieError};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| {
+    let dic = Dic::parse(text);
+    for patterns in [parse_tex(text), dic.patterns.iter().map(String::as_str).collect()] {
+        let patterns: Vec<_> =
+            patterns.into_iter().filter(|p| Trie::try_new([p]).is_ok()).collect();
+        let trie = match Trie::try_new(&patterns) {
+            Ok(trie) => trie,
+            // Larger inputs may legitimately give tries too large to encode.
+            Err(TrieError::TooLarge) => continue,
+            Err(err) => panic!("{err}"),
+        };
+        assert_eq!(Trie::new(trie.patterns()), trie);
+        assert_eq!(Trie::from_bytes(trie.as_bytes().to_vec()).as_ref(), Some(&trie));
+
+        let syllables: Vec<_> = trie.hyphenate_bounded(text, 1, 1).collect();
+        assert_eq!(syllables.concat(), text);
+    }
+});
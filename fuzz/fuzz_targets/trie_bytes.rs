@@ -0,0 +1,22 @@
+#![no_main]
+
+use hypher::Trie;
+use libfuzzer_sys::fuzz_target;
+
+// Traversal states index into the trie data unchecked, so arbitrary bytes
+// can only reach them through `Trie::from_bytes`. This fuzzes that it rejects
+// all malformed data and that the tries it accepts hyphenate without panics.
+fuzz_target!(|input: (&[u8], &str)| {
+    let (data, word) = input;
+    let trie = match Trie::from_bytes(data.to_vec()) {
+        Some(trie) => trie,
+        None => return,
+    };
+    assert_eq!(trie.as_bytes(), data);
+
+    let syllables: Vec<_> = trie.hyphenate_bounded(word, 1, 1).collect();
+    assert_eq!(syllables.concat(), word);
+
+    // A loaded trie is one that compiling its patterns gives back.
+    assert_eq!(Trie::new(trie.patterns()), trie);
+});
@@ -0,0 +1,294 @@
+//! Finding patterns that correct the hyphenation of a few words.
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::{
+    for_each_match, hyphenate, is_char_boundary, lowercase_and_dot, Engine, Lang,
+    Overlay, State, Syllables, Trie, TrieError,
+};
+
+/// Search for a small set of patterns that, laid over a language's own,
+/// hyphenate the given words correctly.
+///
+/// The `fixes` are the correctly hyphenated words, like `hy-phen-ation`. In
+/// contrast to exceptions, patterns also apply to inflected forms of these
+/// words. For each wrong break or missed hyphen, this thus tries the shortest
+/// patterns around it first and takes the first one that keeps the results
+/// on the `reference` words and on the fixes found so far. Only if no such
+/// pattern exists, it falls back to one that matches the whole word. Words
+/// that even this can't fix, e.g. because the pattern can't be encoded, are
+/// reported as unfixed. Reference words that are among the fixes are ignored.
+///
+/// The result is meant to be used with an [`Overlay`]. Words are hyphenated
+/// with the language's default [bounds](Lang::bounds), so hyphens within
+/// them can't be fixed. Languages that are not hyphenated through patterns
+/// can't be fixed at all. To fix Serbian in Latin script, correct
+/// [`Lang::Serbian`] with Cyrillic words and lay the result over
+/// [`Lang::SerbianLatin`].
+///
+/// This is only available when the `alloc` feature is enabled.
+///
+/// # Example
+/// ```
+/// # use hypher::{correct, hyphenate, Lang, Overlay, Trie};
+/// assert_eq!(hyphenate("unicorn", Lang::English).join("-"), "uni-corn");
+///
+/// let correction = correct(Lang::English, &["un-icorn"], &["uniform", "union"]);
+/// assert!(correction.unfixed.is_empty());
+/// assert!(correction.collateral.is_empty());
+///
+/// let overlay = Overlay::new(Lang::English, Trie::new(&correction.patterns));
+/// assert_eq!(overlay.hyphenate("unicorns").join("-"), "un-icorns");
+/// assert_eq!(overlay.hyphenate("uniform").join("-"), "uni-form");
+/// ```
+pub fn correct<F, R>(lang: Lang, fixes: &[F], reference: &[R]) -> Correction
+where
+    F: AsRef<str>,
+    R: AsRef<str>,
+{
+    let mut correction = Correction::default();
+    let root = match patterns_root(lang) {
+        Some(root) => root,
+        None => {
+            correction.unfixed = fixes.iter().map(|fix| fix.as_ref().into()).collect();
+            return correction;
+        }
+    };
+
+    // The fixes themselves are meant to change.
+    let plain = |word: &str| -> String { word.chars().filter(|&c| c != '-').collect() };
+    let words: Vec<String> = fixes.iter().map(|fix| plain(fix.as_ref())).collect();
+    let reference: Vec<_> = reference
+        .iter()
+        .map(|word| word.as_ref())
+        .filter(|word| !words.iter().any(|fix| fix == word))
+        .map(|word| (word, dotted(word), join(hyphenate(word, lang))))
+        .collect();
+
+    let mut patterns = Patterns::default();
+    let mut fixed: Vec<(String, &str)> = vec![];
+    for (fix, word) in fixes.iter().zip(words) {
+        let target = fix.as_ref();
+        let dotted = dotted(&word);
+
+        // Each round raises the level at one position, so no more rounds are
+        // needed than each position going through all levels.
+        let mut trial = patterns.clone();
+        let mut solved = false;
+        for _ in 0..10 * word.len() {
+            // Only the fallback to the whole word may fail to encode, e.g.
+            // when it has too many bytes between two levels.
+            let overlay = match trial.overlay(lang) {
+                Ok(overlay) => overlay,
+                Err(_) => break,
+            };
+            let split = match first_wrong(&overlay, &word, target) {
+                Some(split) => split,
+                None => {
+                    solved = true;
+                    break;
+                }
+            };
+
+            let dot = split + 1;
+            let current = raw_levels(&dotted, root, overlay.trie().root())[dot];
+            if current == 9 {
+                break;
+            }
+
+            // Try the patterns around the position from short to long and
+            // fall back to the whole word.
+            let level = current + 1;
+            let (start, end) = candidates(&dotted, dot)
+                .find(|&(start, end)| {
+                    let letters = &dotted[start..end];
+                    let mut candidate = trial.clone();
+                    candidate.insert(letters, dot - start, level);
+                    let overlay = match candidate.overlay(lang) {
+                        Ok(overlay) => overlay,
+                        Err(_) => return false,
+                    };
+                    let keeps_reference = reference
+                        .iter()
+                        .filter(|(_, dotted, _)| contains(dotted, letters))
+                        .all(|(word, _, before)| {
+                            join(overlay.hyphenate(word)) == *before
+                        });
+                    let keeps_fixed = fixed
+                        .iter()
+                        .all(|(word, target)| join(overlay.hyphenate(word)) == *target);
+                    keeps_reference && keeps_fixed
+                })
+                .unwrap_or((0, dotted.len()));
+
+            trial.insert(&dotted[start..end], dot - start, level);
+        }
+
+        // Drop the patterns for a word that could not be fixed.
+        if solved {
+            patterns = trial;
+            fixed.push((word, target));
+        } else {
+            correction.unfixed.push(target.into());
+        }
+    }
+
+    // Report the reference words whose hyphenation changed after all.
+    // Only patterns that encoded were kept, so this always succeeds.
+    if let Ok(overlay) = patterns.overlay(lang) {
+        for (word, _, before) in &reference {
+            let after = join(overlay.hyphenate(word));
+            if after != *before {
+                correction.collateral.push((before.clone(), after));
+            }
+        }
+    }
+
+    correction.patterns = patterns.strings();
+    correction
+}
+
+/// Patterns that correct the hyphenation of a few words.
+///
+/// This struct is created by [`correct`].
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Correction {
+    /// The additional patterns, like `.a1bc2d`.
+    pub patterns: Vec<String>,
+    /// The words that could not be fixed.
+    pub unfixed: Vec<String>,
+    /// The reference words whose hyphenation changed, before and after.
+    pub collateral: Vec<(String, String)>,
+}
+
+/// The patterns found so far, with the level before each byte of their
+/// letters and at their end.
+#[derive(Default, Clone)]
+struct Patterns(BTreeMap<Vec<u8>, Vec<u8>>);
+
+impl Patterns {
+    /// Add a level before the byte at `dot` of a pattern.
+    fn insert(&mut self, letters: &[u8], dot: usize, level: u8) {
+        let len = letters.len();
+        let levels = self.0.entry(letters.to_vec()).or_insert_with(|| vec![0; len + 1]);
+        levels[dot] = levels[dot].max(level);
+    }
+
+    /// The patterns as strings.
+    fn strings(&self) -> Vec<String> {
+        self.0
+            .iter()
+            .map(|(letters, levels)| {
+                let mut pattern = vec![];
+                for (&b, &level) in letters.iter().zip(levels) {
+                    if level > 0 {
+                        pattern.push(b'0' + level);
+                    }
+                    pattern.push(b);
+                }
+                if levels[letters.len()] > 0 {
+                    pattern.push(b'0' + levels[letters.len()]);
+                }
+                String::from_utf8(pattern).unwrap()
+            })
+            .collect()
+    }
+
+    /// Compile the patterns and lay them over the language's own.
+    fn overlay(&self, lang: Lang) -> Result<Overlay, TrieError> {
+        let trie = Trie::try_new(self.strings())?;
+        Ok(Overlay { lang, trie })
+    }
+}
+
+/// The root of a language's patterns, if it is hyphenated through them.
+fn patterns_root(lang: Lang) -> Option<State<'static>> {
+    match lang.engine() {
+        #[cfg(feature = "serbian-latin")]
+        Engine::Patterns(_) if lang == Lang::SerbianLatin => None,
+        Engine::Patterns(root) => Some(root),
+        _ => None,
+    }
+}
+
+/// The start and end of the candidate patterns around a position of a
+/// dotted word, from short to long.
+fn candidates(dotted: &[u8], dot: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+    let boundary = |i: usize| i == dotted.len() || is_char_boundary(dotted[i]);
+    (1..=dotted.len()).flat_map(move |len| {
+        let first = dot.saturating_sub(len);
+        let last = dot.min(dotted.len() - len);
+        (first..=last)
+            .filter(move |&start| boundary(start) && boundary(start + len))
+            .map(move |start| (start, start + len))
+    })
+}
+
+/// Compute the level before each byte of a dotted word and at its end.
+fn raw_levels(dotted: &[u8], root: State, overlay: State) -> Vec<u8> {
+    let mut levels = vec![0; dotted.len() + 1];
+    for root in [root, overlay] {
+        for_each_match(dotted, root, |start, _, state| {
+            for (offset, level) in state.levels() {
+                let slot = &mut levels[start + offset];
+                *slot = (*slot).max(level);
+            }
+        });
+    }
+    levels
+}
+
+/// Find the first byte offset in the word at which the overlay breaks, but
+/// the target has no hyphen or vice versa.
+fn first_wrong(overlay: &Overlay, word: &str, target: &str) -> Option<usize> {
+    let mut actual = vec![];
+    let mut offset = 0;
+    for syllable in overlay.hyphenate(word) {
+        offset += syllable.len();
+        actual.push(offset);
+    }
+    actual.pop();
+
+    let mut expected = vec![];
+    let mut offset = 0;
+    for c in target.chars() {
+        if c == '-' {
+            expected.push(offset);
+        } else {
+            offset += c.len_utf8();
+        }
+    }
+
+    let wrong = actual.iter().filter(|i| !expected.contains(i));
+    let missed = expected.iter().filter(|i| !actual.contains(i));
+    wrong
+        .chain(missed)
+        .copied()
+        .filter(|&i| i > 0 && i < word.len())
+        .min()
+}
+
+/// The lowercased word with a dot before and after it.
+fn dotted(word: &str) -> Vec<u8> {
+    lowercase_and_dot(word).as_slice().to_vec()
+}
+
+/// Join syllables with hyphens.
+fn join(syllables: Syllables) -> String {
+    let mut text = String::new();
+    for (i, syllable) in syllables.enumerate() {
+        if i > 0 {
+            text.push('-');
+        }
+        text.push_str(syllable);
+    }
+    text
+}
+
+/// Whether the dotted word contains the letters.
+fn contains(dotted: &[u8], letters: &[u8]) -> bool {
+    dotted.windows(letters.len()).any(|window| window == letters)
+}
@@ -0,0 +1,159 @@
+//! Measuring hyphenation quality against gold-standard hyphenations.
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::{hyphenate, Clusters, Lang};
+
+/// Compare a language's hyphenation with gold-standard hyphenations.
+///
+/// The `gold` words are hyphenated with `-`, like `hy-phen-ation`, typically
+/// one per line of a word list. Blank lines are skipped. Each word is
+/// hyphenated with [`hyphenate`] and thus with the default
+/// [bounds](Lang::bounds) of the language. Gold hyphens within these bounds
+/// are not counted, as they can never be found.
+///
+/// This is only available when the `alloc` feature is enabled.
+///
+/// # Example
+/// ```
+/// # use hypher::{evaluate, Lang};
+/// let gold = "ex-ten-sive\nhy-phen-a-tion\npres-en-ta-tion\n";
+/// let eval = evaluate(Lang::English, gold.lines());
+/// assert_eq!(eval.words, 3);
+/// assert_eq!(eval.exact, 1);
+/// assert_eq!(eval.precision(), 6.0 / 7.0);
+/// assert_eq!(eval.recall(), 6.0 / 8.0);
+///
+/// let worst = &eval.mismatches[0];
+/// assert_eq!(worst.gold, "pres-en-ta-tion");
+/// assert_eq!(worst.actual, "pre-sen-ta-tion");
+/// ```
+pub fn evaluate<I>(lang: Lang, gold: I) -> Evaluation
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut eval = Evaluation::default();
+    for line in gold {
+        let target = line.as_ref().trim();
+        if target.is_empty() {
+            continue;
+        }
+
+        // Collect the byte offsets of the gold hyphens in the plain word.
+        let mut word = String::new();
+        let mut expected = vec![];
+        for c in target.chars() {
+            if c == '-' {
+                expected.push(word.len());
+            } else {
+                word.push(c);
+            }
+        }
+
+        // Hyphens at the edges or outside of the bounds can never be found.
+        let (left_min, right_min) = lang.bounds();
+        let (min_idx, max_idx) = Clusters::new(&word).byte_bounds(left_min, right_min);
+        expected.retain(|&i| i > 0 && i < word.len() && i + 1 >= min_idx && i < max_idx);
+
+        let mut actual = vec![];
+        let mut offset = 0;
+        for syllable in hyphenate(&word, lang) {
+            if offset > 0 {
+                actual.push(offset);
+            }
+            offset += syllable.len();
+        }
+
+        let correct = actual.iter().filter(|i| expected.contains(i)).count();
+        let wrong = actual.len() - correct;
+        let missed = expected.len() - correct;
+
+        eval.words += 1;
+        eval.correct += correct;
+        eval.wrong += wrong;
+        eval.missed += missed;
+        if wrong == 0 && missed == 0 {
+            eval.exact += 1;
+        } else {
+            eval.mismatches.push(Mismatch {
+                gold: target.into(),
+                actual: hyphenate(&word, lang).join("-"),
+                wrong,
+                missed,
+            });
+        }
+    }
+
+    // Put the words with the most errors first. The sort is stable, so ties
+    // keep the order of the gold list.
+    eval.mismatches
+        .sort_by_key(|m| core::cmp::Reverse(m.wrong + m.missed));
+    eval
+}
+
+/// How well a language hyphenates a list of gold-standard hyphenations.
+///
+/// This struct is created by [`evaluate`].
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Evaluation {
+    /// The number of evaluated words.
+    pub words: usize,
+    /// The number of words that were hyphenated exactly like in the gold list.
+    pub exact: usize,
+    /// The number of gold hyphens that were found.
+    pub correct: usize,
+    /// The number of breaks that were found, but are no gold hyphens.
+    pub wrong: usize,
+    /// The number of gold hyphens that were not found.
+    pub missed: usize,
+    /// The words that were not hyphenated exactly, with the most errors first.
+    pub mismatches: Vec<Mismatch>,
+}
+
+impl Evaluation {
+    /// The share of found breaks that are gold hyphens.
+    ///
+    /// Is one if no breaks were found.
+    pub fn precision(&self) -> f64 {
+        ratio(self.correct, self.correct + self.wrong)
+    }
+
+    /// The share of gold hyphens that were found.
+    ///
+    /// Is one if there are no gold hyphens.
+    pub fn recall(&self) -> f64 {
+        ratio(self.correct, self.correct + self.missed)
+    }
+
+    /// The share of words that were hyphenated exactly.
+    ///
+    /// Is one if there are no words.
+    pub fn accuracy(&self) -> f64 {
+        ratio(self.exact, self.words)
+    }
+}
+
+/// A word that was not hyphenated like in the gold list.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Mismatch {
+    /// The gold hyphenation, like `hy-phen-ation`.
+    pub gold: String,
+    /// The actual hyphenation, like `hy-phena-tion`.
+    pub actual: String,
+    /// The number of breaks that are no gold hyphens.
+    pub wrong: usize,
+    /// The number of gold hyphens that were not found.
+    pub missed: usize,
+}
+
+/// Divide, but treat an empty whole as complete.
+fn ratio(part: usize, whole: usize) -> f64 {
+    if whole == 0 {
+        1.0
+    } else {
+        part as f64 / whole as f64
+    }
+}
@@ -3,9 +3,11 @@
 
 /// A language you can hyphenate in.
 ///
-/// Lists for each language also the ISO 639-1 two
-/// letter language code and the ISO 15924 four letter
-/// script code.
+/// Lists for each language also the ISO 639 language
+/// code and the ISO 15924 four letter script code.
+/// Languages without a two letter ISO 639-1 code or
+/// variants of another language can't be selected
+/// through [`Lang::from_iso`].
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 #[non_exhaustive]
 pub enum Lang {
@@ -15,9 +17,15 @@ pub enum Lang {
     /// Hyphenation for _Albanian._ (Code: `sq`, Script, `Latn`, Feature: `albanian`)
     #[cfg(feature = "albanian")]
     Albanian,
+    /// Hyphenation for _Armenian._ (Code: `hy`, Script, `Armn`, Feature: `armenian`)
+    #[cfg(feature = "armenian")]
+    Armenian,
     /// Hyphenation for _Assamese._ (Code: `as`, Script, `Beng`, Feature: `assamese`)
     #[cfg(feature = "assamese")]
     Assamese,
+    /// Hyphenation for _Basque._ (Code: `eu`, Script, `Latn`, Feature: `basque`)
+    #[cfg(feature = "basque")]
+    Basque,
     /// Hyphenation for _Belarusian._ (Code: `be`, Script, `Cyrl`, Feature: `belarusian`)
     #[cfg(feature = "belarusian")]
     Belarusian,
@@ -27,9 +35,21 @@ pub enum Lang {
     /// Hyphenation for _Bulgarian._ (Code: `bg`, Script, `Cyrl`, Feature: `bulgarian`)
     #[cfg(feature = "bulgarian")]
     Bulgarian,
+    /// Hyphenation for _Canadian Syllabics._ (Code: `mul-Cans`, Alias: `iu`, Alias: `cr`, Alias: `oj`, Script, `Cans`, Feature: `canadian-syllabics`)
+    #[cfg(feature = "canadian-syllabics")]
+    CanadianSyllabics,
     /// Hyphenation for _Catalan._ (Code: `ca`, Script, `Latn`, Feature: `catalan`)
     #[cfg(feature = "catalan")]
     Catalan,
+    /// Hyphenation for _Cherokee._ (Code: `chr`, Script, `Cher`, Feature: `cherokee`)
+    #[cfg(feature = "cherokee")]
+    Cherokee,
+    /// Hyphenation for _Church Slavonic._ (Code: `cu`, Script, `Cyrs`, Feature: `church-slavonic`)
+    #[cfg(feature = "church-slavonic")]
+    ChurchSlavonic,
+    /// Hyphenation for _Coptic._ (Code: `cop`, Script, `Copt`, Feature: `coptic`)
+    #[cfg(feature = "coptic")]
+    Coptic,
     /// Hyphenation for _Croatian._ (Code: `hr`, Script, `Latn`, Feature: `croatian`)
     #[cfg(feature = "croatian")]
     Croatian,
@@ -45,15 +65,24 @@ pub enum Lang {
     /// Hyphenation for _English._ (Code: `en`, Script, `Latn`, Feature: `english`)
     #[cfg(feature = "english")]
     English,
+    /// Hyphenation for _Esperanto._ (Code: `eo`, Script, `Latn`, Feature: `esperanto`)
+    #[cfg(feature = "esperanto")]
+    Esperanto,
     /// Hyphenation for _Estonian._ (Code: `et`, Script, `Latn`, Feature: `estonian`)
     #[cfg(feature = "estonian")]
     Estonian,
+    /// Hyphenation for _Ethiopic._ (Code: `mul-Ethi`, Alias: `am`, Alias: `ti`, Script, `Ethi`, Feature: `ethiopic`)
+    #[cfg(feature = "ethiopic")]
+    Ethiopic,
     /// Hyphenation for _Finnish._ (Code: `fi`, Script, `Latn`, Feature: `finnish`)
     #[cfg(feature = "finnish")]
     Finnish,
     /// Hyphenation for _French._ (Code: `fr`, Script, `Latn`, Feature: `french`)
     #[cfg(feature = "french")]
     French,
+    /// Hyphenation for _Friulian._ (Code: `fur`, Script, `Latn`, Feature: `friulian`)
+    #[cfg(feature = "friulian")]
+    Friulian,
     /// Hyphenation for _Galician._ (Code: `gl`, Script, `Latn`, Feature: `galician`)
     #[cfg(feature = "galician")]
     Galician,
@@ -78,18 +107,45 @@ pub enum Lang {
     /// Hyphenation for _Icelandic._ (Code: `is`, Script, `Latn`, Feature: `icelandic`)
     #[cfg(feature = "icelandic")]
     Icelandic,
+    /// Hyphenation for _Indonesian._ (Code: `id`, Alias: `ms`, Script, `Latn`, Feature: `indonesian`)
+    #[cfg(feature = "indonesian")]
+    Indonesian,
+    /// Hyphenation for _Interlingua._ (Code: `ia`, Script, `Latn`, Feature: `interlingua`)
+    #[cfg(feature = "interlingua")]
+    Interlingua,
+    /// Hyphenation for _Irish._ (Code: `ga`, Script, `Latn`, Feature: `irish`)
+    #[cfg(feature = "irish")]
+    Irish,
     /// Hyphenation for _Italian._ (Code: `it`, Script, `Latn`, Feature: `italian`)
     #[cfg(feature = "italian")]
     Italian,
     /// Hyphenation for _Kannada._ (Code: `kn`, Script, `Knda`, Feature: `kannada`)
     #[cfg(feature = "kannada")]
     Kannada,
+    /// Hyphenation for _Khmer._ (Code: `km`, Script, `Khmr`, Feature: `khmer`)
+    #[cfg(feature = "khmer")]
+    Khmer,
+    /// Hyphenation for _Korean._ (Code: `ko`, Script, `Kore`, Feature: `korean`)
+    #[cfg(feature = "korean")]
+    Korean,
     /// Hyphenation for _Kurmanji._ (Code: `ku`, Script, `Latn`, Feature: `kurmanji`)
     #[cfg(feature = "kurmanji")]
     Kurmanji,
+    /// Hyphenation for _Lao._ (Code: `lo`, Script, `Laoo`, Feature: `lao`)
+    #[cfg(feature = "lao")]
+    Lao,
     /// Hyphenation for _Latin._ (Code: `la`, Script, `Latn`, Feature: `latin`)
     #[cfg(feature = "latin")]
     Latin,
+    /// Hyphenation for _Latin Classic._ (Code: `la-x-classic`, Script, `Latn`, Feature: `latin-classic`)
+    #[cfg(feature = "latin-classic")]
+    LatinClassic,
+    /// Hyphenation for _Latin Liturgical._ (Code: `la-x-liturgic`, Script, `Latn`, Feature: `latin-liturgical`)
+    #[cfg(feature = "latin-liturgical")]
+    LatinLiturgical,
+    /// Hyphenation for _Latvian._ (Code: `lv`, Script, `Latn`, Feature: `latvian`)
+    #[cfg(feature = "latvian")]
+    Latvian,
     /// Hyphenation for _Lithuanian._ (Code: `lt`, Script, `Latn`, Feature: `lithuanian`)
     #[cfg(feature = "lithuanian")]
     Lithuanian,
@@ -102,21 +158,51 @@ pub enum Lang {
     /// Hyphenation for _Mongolian._ (Code: `mn`, Script, `Cyrl`, Feature: `mongolian`)
     #[cfg(feature = "mongolian")]
     Mongolian,
-    /// Hyphenation for _Norwegian._ (Code: `no`, Alias: `nb`, Alias: `nn`, Script, `Latn`, Feature: `norwegian`)
+    /// Hyphenation for _Norwegian._ (Code: `no`, Script, `Latn`, Feature: `norwegian`)
+    ///
+    /// Uses the shared patterns of `hyph-no`, so it
+    /// hyphenates like Norwegian Bokmål and Norwegian Nynorsk.
     #[cfg(feature = "norwegian")]
     Norwegian,
+    /// Hyphenation for _Norwegian Bokmål._ (Code: `nb`, Script, `Latn`, Feature: `norwegian-bokmal`)
+    ///
+    /// Uses the shared patterns of `hyph-no`, so it
+    /// hyphenates like Norwegian and Norwegian Nynorsk.
+    #[cfg(feature = "norwegian-bokmal")]
+    NorwegianBokmal,
+    /// Hyphenation for _Norwegian Nynorsk._ (Code: `nn`, Script, `Latn`, Feature: `norwegian-nynorsk`)
+    ///
+    /// Uses the shared patterns of `hyph-no`, so it
+    /// hyphenates like Norwegian and Norwegian Bokmål.
+    #[cfg(feature = "norwegian-nynorsk")]
+    NorwegianNynorsk,
+    /// Hyphenation for _Occitan._ (Code: `oc`, Script, `Latn`, Feature: `occitan`)
+    #[cfg(feature = "occitan")]
+    Occitan,
     /// Hyphenation for _Oriya._ (Code: `or`, Script, `Orya`, Feature: `oriya`)
     #[cfg(feature = "oriya")]
     Oriya,
+    /// Hyphenation for _Pali._ (Code: `pi`, Script, `Latn`, Feature: `pali`)
+    #[cfg(feature = "pali")]
+    Pali,
     /// Hyphenation for _Panjabi._ (Code: `pa`, Script, `Guru`, Feature: `panjabi`)
     #[cfg(feature = "panjabi")]
     Panjabi,
+    /// Hyphenation for _Piedmontese._ (Code: `pms`, Script, `Latn`, Feature: `piedmontese`)
+    #[cfg(feature = "piedmontese")]
+    Piedmontese,
     /// Hyphenation for _Polish._ (Code: `pl`, Script, `Latn`, Feature: `polish`)
     #[cfg(feature = "polish")]
     Polish,
     /// Hyphenation for _Portuguese._ (Code: `pt`, Script, `Latn`, Feature: `portuguese`)
     #[cfg(feature = "portuguese")]
     Portuguese,
+    /// Hyphenation for _Romanian._ (Code: `ro`, Script, `Latn`, Feature: `romanian`)
+    #[cfg(feature = "romanian")]
+    Romanian,
+    /// Hyphenation for _Romansh._ (Code: `rm`, Script, `Latn`, Feature: `romansh`)
+    #[cfg(feature = "romansh")]
+    Romansh,
     /// Hyphenation for _Russian._ (Code: `ru`, Script, `Cyrl`, Feature: `russian`)
     #[cfg(feature = "russian")]
     Russian,
@@ -126,6 +212,9 @@ pub enum Lang {
     /// Hyphenation for _Serbian._ (Code: `sr`, Script, `Cyrl`, Feature: `serbian`)
     #[cfg(feature = "serbian")]
     Serbian,
+    /// Hyphenation for _Serbian Latin._ (Code: `sr-Latn`, Script, `Latn`, Feature: `serbian-latin`)
+    #[cfg(feature = "serbian-latin")]
+    SerbianLatin,
     /// Hyphenation for _Slovak._ (Code: `sk`, Script, `Latn`, Feature: `slovak`)
     #[cfg(feature = "slovak")]
     Slovak,
@@ -144,6 +233,9 @@ pub enum Lang {
     /// Hyphenation for _Telugu._ (Code: `te`, Script, `Telu`, Feature: `telugu`)
     #[cfg(feature = "telugu")]
     Telugu,
+    /// Hyphenation for _Thai._ (Code: `th`, Script, `Thai`, Feature: `thai`)
+    #[cfg(feature = "thai")]
+    Thai,
     /// Hyphenation for _Turkish._ (Code: `tr`, Script, `Latn`, Feature: `turkish`)
     #[cfg(feature = "turkish")]
     Turkish,
@@ -153,9 +245,173 @@ pub enum Lang {
     /// Hyphenation for _Ukrainian._ (Code: `uk`, Script, `Cyrl`, Feature: `ukrainian`)
     #[cfg(feature = "ukrainian")]
     Ukrainian,
+    /// Hyphenation for _Upper Sorbian._ (Code: `hsb`, Script, `Latn`, Feature: `upper-sorbian`)
+    #[cfg(feature = "upper-sorbian")]
+    UpperSorbian,
+    /// Hyphenation for _Welsh._ (Code: `cy`, Script, `Latn`, Feature: `welsh`)
+    #[cfg(feature = "welsh")]
+    Welsh,
 }
 
 impl Lang {
+    /// All languages enabled through features.
+    pub const ALL: &'static [Self] = &[
+        #[cfg(feature = "afrikaans")]
+        Self::Afrikaans,
+        #[cfg(feature = "albanian")]
+        Self::Albanian,
+        #[cfg(feature = "armenian")]
+        Self::Armenian,
+        #[cfg(feature = "assamese")]
+        Self::Assamese,
+        #[cfg(feature = "basque")]
+        Self::Basque,
+        #[cfg(feature = "belarusian")]
+        Self::Belarusian,
+        #[cfg(feature = "bengali")]
+        Self::Bengali,
+        #[cfg(feature = "bulgarian")]
+        Self::Bulgarian,
+        #[cfg(feature = "canadian-syllabics")]
+        Self::CanadianSyllabics,
+        #[cfg(feature = "catalan")]
+        Self::Catalan,
+        #[cfg(feature = "cherokee")]
+        Self::Cherokee,
+        #[cfg(feature = "church-slavonic")]
+        Self::ChurchSlavonic,
+        #[cfg(feature = "coptic")]
+        Self::Coptic,
+        #[cfg(feature = "croatian")]
+        Self::Croatian,
+        #[cfg(feature = "czech")]
+        Self::Czech,
+        #[cfg(feature = "danish")]
+        Self::Danish,
+        #[cfg(feature = "dutch")]
+        Self::Dutch,
+        #[cfg(feature = "english")]
+        Self::English,
+        #[cfg(feature = "esperanto")]
+        Self::Esperanto,
+        #[cfg(feature = "estonian")]
+        Self::Estonian,
+        #[cfg(feature = "ethiopic")]
+        Self::Ethiopic,
+        #[cfg(feature = "finnish")]
+        Self::Finnish,
+        #[cfg(feature = "french")]
+        Self::French,
+        #[cfg(feature = "friulian")]
+        Self::Friulian,
+        #[cfg(feature = "galician")]
+        Self::Galician,
+        #[cfg(feature = "georgian")]
+        Self::Georgian,
+        #[cfg(feature = "german")]
+        Self::German,
+        #[cfg(feature = "greek")]
+        Self::Greek,
+        #[cfg(feature = "gujarati")]
+        Self::Gujarati,
+        #[cfg(feature = "hindi")]
+        Self::Hindi,
+        #[cfg(feature = "hungarian")]
+        Self::Hungarian,
+        #[cfg(feature = "icelandic")]
+        Self::Icelandic,
+        #[cfg(feature = "indonesian")]
+        Self::Indonesian,
+        #[cfg(feature = "interlingua")]
+        Self::Interlingua,
+        #[cfg(feature = "irish")]
+        Self::Irish,
+        #[cfg(feature = "italian")]
+        Self::Italian,
+        #[cfg(feature = "kannada")]
+        Self::Kannada,
+        #[cfg(feature = "khmer")]
+        Self::Khmer,
+        #[cfg(feature = "korean")]
+        Self::Korean,
+        #[cfg(feature = "kurmanji")]
+        Self::Kurmanji,
+        #[cfg(feature = "lao")]
+        Self::Lao,
+        #[cfg(feature = "latin")]
+        Self::Latin,
+        #[cfg(feature = "latin-classic")]
+        Self::LatinClassic,
+        #[cfg(feature = "latin-liturgical")]
+        Self::LatinLiturgical,
+        #[cfg(feature = "latvian")]
+        Self::Latvian,
+        #[cfg(feature = "lithuanian")]
+        Self::Lithuanian,
+        #[cfg(feature = "malayalam")]
+        Self::Malayalam,
+        #[cfg(feature = "marathi")]
+        Self::Marathi,
+        #[cfg(feature = "mongolian")]
+        Self::Mongolian,
+        #[cfg(feature = "norwegian")]
+        Self::Norwegian,
+        #[cfg(feature = "norwegian-bokmal")]
+        Self::NorwegianBokmal,
+        #[cfg(feature = "norwegian-nynorsk")]
+        Self::NorwegianNynorsk,
+        #[cfg(feature = "occitan")]
+        Self::Occitan,
+        #[cfg(feature = "oriya")]
+        Self::Oriya,
+        #[cfg(feature = "pali")]
+        Self::Pali,
+        #[cfg(feature = "panjabi")]
+        Self::Panjabi,
+        #[cfg(feature = "piedmontese")]
+        Self::Piedmontese,
+        #[cfg(feature = "polish")]
+        Self::Polish,
+        #[cfg(feature = "portuguese")]
+        Self::Portuguese,
+        #[cfg(feature = "romanian")]
+        Self::Romanian,
+        #[cfg(feature = "romansh")]
+        Self::Romansh,
+        #[cfg(feature = "russian")]
+        Self::Russian,
+        #[cfg(feature = "sanskrit")]
+        Self::Sanskrit,
+        #[cfg(feature = "serbian")]
+        Self::Serbian,
+        #[cfg(feature = "serbian-latin")]
+        Self::SerbianLatin,
+        #[cfg(feature = "slovak")]
+        Self::Slovak,
+        #[cfg(feature = "slovenian")]
+        Self::Slovenian,
+        #[cfg(feature = "spanish")]
+        Self::Spanish,
+        #[cfg(feature = "swedish")]
+        Self::Swedish,
+        #[cfg(feature = "tamil")]
+        Self::Tamil,
+        #[cfg(feature = "telugu")]
+        Self::Telugu,
+        #[cfg(feature = "thai")]
+        Self::Thai,
+        #[cfg(feature = "turkish")]
+        Self::Turkish,
+        #[cfg(feature = "turkmen")]
+        Self::Turkmen,
+        #[cfg(feature = "ukrainian")]
+        Self::Ukrainian,
+        #[cfg(feature = "upper-sorbian")]
+        Self::UpperSorbian,
+        #[cfg(feature = "welsh")]
+        Self::Welsh,
+    ];
+
     /// Select a language using its ISO 639-1 code.
     pub fn from_iso(code: [u8; 2]) -> Option<Self> {
         match &code {
@@ -163,16 +419,28 @@ impl Lang {
             b"af" => Some(Self::Afrikaans),
             #[cfg(feature = "albanian")]
             b"sq" => Some(Self::Albanian),
+            #[cfg(feature = "armenian")]
+            b"hy" => Some(Self::Armenian),
             #[cfg(feature = "assamese")]
             b"as" => Some(Self::Assamese),
+            #[cfg(feature = "basque")]
+            b"eu" => Some(Self::Basque),
             #[cfg(feature = "belarusian")]
             b"be" => Some(Self::Belarusian),
             #[cfg(feature = "bengali")]
             b"bn" => Some(Self::Bengali),
             #[cfg(feature = "bulgarian")]
             b"bg" => Some(Self::Bulgarian),
+            #[cfg(feature = "canadian-syllabics")]
+            b"iu" => Some(Self::CanadianSyllabics),
+            #[cfg(feature = "canadian-syllabics")]
+            b"cr" => Some(Self::CanadianSyllabics),
+            #[cfg(feature = "canadian-syllabics")]
+            b"oj" => Some(Self::CanadianSyllabics),
             #[cfg(feature = "catalan")]
             b"ca" => Some(Self::Catalan),
+            #[cfg(feature = "church-slavonic")]
+            b"cu" => Some(Self::ChurchSlavonic),
             #[cfg(feature = "croatian")]
             b"hr" => Some(Self::Croatian),
             #[cfg(feature = "czech")]
@@ -183,8 +451,14 @@ impl Lang {
             b"nl" => Some(Self::Dutch),
             #[cfg(feature = "english")]
             b"en" => Some(Self::English),
+            #[cfg(feature = "esperanto")]
+            b"eo" => Some(Self::Esperanto),
             #[cfg(feature = "estonian")]
             b"et" => Some(Self::Estonian),
+            #[cfg(feature = "ethiopic")]
+            b"am" => Some(Self::Ethiopic),
+            #[cfg(feature = "ethiopic")]
+            b"ti" => Some(Self::Ethiopic),
             #[cfg(feature = "finnish")]
             b"fi" => Some(Self::Finnish),
             #[cfg(feature = "french")]
@@ -205,14 +479,30 @@ impl Lang {
             b"hu" => Some(Self::Hungarian),
             #[cfg(feature = "icelandic")]
             b"is" => Some(Self::Icelandic),
+            #[cfg(feature = "indonesian")]
+            b"id" => Some(Self::Indonesian),
+            #[cfg(feature = "indonesian")]
+            b"ms" => Some(Self::Indonesian),
+            #[cfg(feature = "interlingua")]
+            b"ia" => Some(Self::Interlingua),
+            #[cfg(feature = "irish")]
+            b"ga" => Some(Self::Irish),
             #[cfg(feature = "italian")]
             b"it" => Some(Self::Italian),
             #[cfg(feature = "kannada")]
             b"kn" => Some(Self::Kannada),
+            #[cfg(feature = "khmer")]
+            b"km" => Some(Self::Khmer),
+            #[cfg(feature = "korean")]
+            b"ko" => Some(Self::Korean),
             #[cfg(feature = "kurmanji")]
             b"ku" => Some(Self::Kurmanji),
+            #[cfg(feature = "lao")]
+            b"lo" => Some(Self::Lao),
             #[cfg(feature = "latin")]
             b"la" => Some(Self::Latin),
+            #[cfg(feature = "latvian")]
+            b"lv" => Some(Self::Latvian),
             #[cfg(feature = "lithuanian")]
             b"lt" => Some(Self::Lithuanian),
             #[cfg(feature = "malayalam")]
@@ -223,18 +513,26 @@ impl Lang {
             b"mn" => Some(Self::Mongolian),
             #[cfg(feature = "norwegian")]
             b"no" => Some(Self::Norwegian),
-            #[cfg(feature = "norwegian")]
-            b"nb" => Some(Self::Norwegian),
-            #[cfg(feature = "norwegian")]
-            b"nn" => Some(Self::Norwegian),
+            #[cfg(feature = "norwegian-bokmal")]
+            b"nb" => Some(Self::NorwegianBokmal),
+            #[cfg(feature = "norwegian-nynorsk")]
+            b"nn" => Some(Self::NorwegianNynorsk),
+            #[cfg(feature = "occitan")]
+            b"oc" => Some(Self::Occitan),
             #[cfg(feature = "oriya")]
             b"or" => Some(Self::Oriya),
+            #[cfg(feature = "pali")]
+            b"pi" => Some(Self::Pali),
             #[cfg(feature = "panjabi")]
             b"pa" => Some(Self::Panjabi),
             #[cfg(feature = "polish")]
             b"pl" => Some(Self::Polish),
             #[cfg(feature = "portuguese")]
             b"pt" => Some(Self::Portuguese),
+            #[cfg(feature = "romanian")]
+            b"ro" => Some(Self::Romanian),
+            #[cfg(feature = "romansh")]
+            b"rm" => Some(Self::Romansh),
             #[cfg(feature = "russian")]
             b"ru" => Some(Self::Russian),
             #[cfg(feature = "sanskrit")]
@@ -253,36 +551,71 @@ impl Lang {
             b"ta" => Some(Self::Tamil),
             #[cfg(feature = "telugu")]
             b"te" => Some(Self::Telugu),
+            #[cfg(feature = "thai")]
+            b"th" => Some(Self::Thai),
             #[cfg(feature = "turkish")]
             b"tr" => Some(Self::Turkish),
             #[cfg(feature = "turkmen")]
             b"tk" => Some(Self::Turkmen),
             #[cfg(feature = "ukrainian")]
             b"uk" => Some(Self::Ukrainian),
+            #[cfg(feature = "welsh")]
+            b"cy" => Some(Self::Welsh),
             _ => None,
         }
     }
 
-    /// The default number of chars to each side between
-    /// which breaking is forbidden.
+    /// Select a language using its ISO 639-1 code and its
+    /// ISO 15924 script code (e.g. `Latn`).
     ///
-    /// This follows typographic conventions.
+    /// Falls back to [`from_iso`](Self::from_iso) if there
+    /// is no language specific to the script.
+    pub fn from_iso_and_script(
+        code: [u8; 2],
+        script: [u8; 4],
+    ) -> Option<Self> {
+        let script = script.map(|b| b.to_ascii_lowercase());
+        match (&code, &script) {
+            #[cfg(feature = "serbian-latin")]
+            (b"sr", b"latn") => Some(Self::SerbianLatin),
+            _ => Self::from_iso(code),
+        }
+    }
+
+    /// The default number of grapheme clusters to each
+    /// side between which breaking is forbidden.
+    ///
+    /// These are the `hyphenmins` for typesetting stated
+    /// in the language's pattern file, if there are any.
+    /// Otherwise, they follow typographic conventions.
     pub fn bounds(self) -> (usize, usize) {
         match self {
             #[cfg(feature = "afrikaans")]
             Self::Afrikaans => (1, 2),
             #[cfg(feature = "albanian")]
             Self::Albanian => (2, 2),
+            #[cfg(feature = "armenian")]
+            Self::Armenian => (1, 2),
             #[cfg(feature = "assamese")]
-            Self::Assamese => (2, 2),
+            Self::Assamese => (1, 1),
+            #[cfg(feature = "basque")]
+            Self::Basque => (2, 2),
             #[cfg(feature = "belarusian")]
             Self::Belarusian => (2, 2),
             #[cfg(feature = "bengali")]
-            Self::Bengali => (2, 2),
+            Self::Bengali => (1, 1),
             #[cfg(feature = "bulgarian")]
             Self::Bulgarian => (2, 2),
+            #[cfg(feature = "canadian-syllabics")]
+            Self::CanadianSyllabics => (1, 1),
             #[cfg(feature = "catalan")]
             Self::Catalan => (2, 2),
+            #[cfg(feature = "cherokee")]
+            Self::Cherokee => (1, 1),
+            #[cfg(feature = "church-slavonic")]
+            Self::ChurchSlavonic => (1, 2),
+            #[cfg(feature = "coptic")]
+            Self::Coptic => (1, 1),
             #[cfg(feature = "croatian")]
             Self::Croatian => (2, 2),
             #[cfg(feature = "czech")]
@@ -293,12 +626,18 @@ impl Lang {
             Self::Dutch => (2, 2),
             #[cfg(feature = "english")]
             Self::English => (2, 3),
+            #[cfg(feature = "esperanto")]
+            Self::Esperanto => (2, 2),
             #[cfg(feature = "estonian")]
             Self::Estonian => (2, 3),
+            #[cfg(feature = "ethiopic")]
+            Self::Ethiopic => (1, 1),
             #[cfg(feature = "finnish")]
             Self::Finnish => (2, 2),
             #[cfg(feature = "french")]
             Self::French => (2, 2),
+            #[cfg(feature = "friulian")]
+            Self::Friulian => (2, 2),
             #[cfg(feature = "galician")]
             Self::Galician => (2, 2),
             #[cfg(feature = "georgian")]
@@ -308,45 +647,79 @@ impl Lang {
             #[cfg(feature = "greek")]
             Self::Greek => (1, 1),
             #[cfg(feature = "gujarati")]
-            Self::Gujarati => (2, 2),
+            Self::Gujarati => (1, 1),
             #[cfg(feature = "hindi")]
-            Self::Hindi => (2, 2),
+            Self::Hindi => (1, 1),
             #[cfg(feature = "hungarian")]
             Self::Hungarian => (2, 2),
             #[cfg(feature = "icelandic")]
             Self::Icelandic => (2, 2),
+            #[cfg(feature = "indonesian")]
+            Self::Indonesian => (2, 2),
+            #[cfg(feature = "interlingua")]
+            Self::Interlingua => (2, 2),
+            #[cfg(feature = "irish")]
+            Self::Irish => (2, 3),
             #[cfg(feature = "italian")]
             Self::Italian => (2, 2),
             #[cfg(feature = "kannada")]
-            Self::Kannada => (2, 2),
+            Self::Kannada => (1, 1),
+            #[cfg(feature = "khmer")]
+            Self::Khmer => (1, 1),
+            #[cfg(feature = "korean")]
+            Self::Korean => (1, 1),
             #[cfg(feature = "kurmanji")]
             Self::Kurmanji => (2, 2),
+            #[cfg(feature = "lao")]
+            Self::Lao => (1, 1),
             #[cfg(feature = "latin")]
             Self::Latin => (2, 2),
+            #[cfg(feature = "latin-classic")]
+            Self::LatinClassic => (2, 2),
+            #[cfg(feature = "latin-liturgical")]
+            Self::LatinLiturgical => (2, 2),
+            #[cfg(feature = "latvian")]
+            Self::Latvian => (2, 2),
             #[cfg(feature = "lithuanian")]
             Self::Lithuanian => (2, 2),
             #[cfg(feature = "malayalam")]
-            Self::Malayalam => (2, 2),
+            Self::Malayalam => (1, 1),
             #[cfg(feature = "marathi")]
-            Self::Marathi => (2, 2),
+            Self::Marathi => (1, 1),
             #[cfg(feature = "mongolian")]
             Self::Mongolian => (2, 2),
             #[cfg(feature = "norwegian")]
             Self::Norwegian => (2, 2),
+            #[cfg(feature = "norwegian-bokmal")]
+            Self::NorwegianBokmal => (2, 2),
+            #[cfg(feature = "norwegian-nynorsk")]
+            Self::NorwegianNynorsk => (2, 2),
+            #[cfg(feature = "occitan")]
+            Self::Occitan => (2, 2),
             #[cfg(feature = "oriya")]
-            Self::Oriya => (2, 2),
+            Self::Oriya => (1, 1),
+            #[cfg(feature = "pali")]
+            Self::Pali => (1, 2),
             #[cfg(feature = "panjabi")]
-            Self::Panjabi => (2, 2),
+            Self::Panjabi => (1, 1),
+            #[cfg(feature = "piedmontese")]
+            Self::Piedmontese => (2, 2),
             #[cfg(feature = "polish")]
             Self::Polish => (2, 2),
             #[cfg(feature = "portuguese")]
             Self::Portuguese => (2, 3),
+            #[cfg(feature = "romanian")]
+            Self::Romanian => (2, 2),
+            #[cfg(feature = "romansh")]
+            Self::Romansh => (2, 2),
             #[cfg(feature = "russian")]
             Self::Russian => (2, 2),
             #[cfg(feature = "sanskrit")]
-            Self::Sanskrit => (2, 2),
+            Self::Sanskrit => (1, 3),
             #[cfg(feature = "serbian")]
             Self::Serbian => (2, 2),
+            #[cfg(feature = "serbian-latin")]
+            Self::SerbianLatin => (2, 2),
             #[cfg(feature = "slovak")]
             Self::Slovak => (2, 3),
             #[cfg(feature = "slovenian")]
@@ -356,116 +729,1419 @@ impl Lang {
             #[cfg(feature = "swedish")]
             Self::Swedish => (2, 2),
             #[cfg(feature = "tamil")]
-            Self::Tamil => (2, 2),
+            Self::Tamil => (1, 1),
             #[cfg(feature = "telugu")]
-            Self::Telugu => (2, 2),
+            Self::Telugu => (1, 1),
+            #[cfg(feature = "thai")]
+            Self::Thai => (1, 1),
             #[cfg(feature = "turkish")]
             Self::Turkish => (2, 2),
             #[cfg(feature = "turkmen")]
             Self::Turkmen => (2, 2),
             #[cfg(feature = "ukrainian")]
             Self::Ukrainian => (2, 2),
+            #[cfg(feature = "upper-sorbian")]
+            Self::UpperSorbian => (2, 2),
+            #[cfg(feature = "welsh")]
+            Self::Welsh => (2, 3),
+        }
+    }
+
+    /// Information about the language's patterns from
+    /// the header of their file.
+    ///
+    /// Returns `None` if the file has no such header.
+    pub fn metadata(self) -> Option<Metadata> {
+        match self {
+            #[cfg(feature = "afrikaans")]
+            Self::Afrikaans => Some(Metadata {
+                title: "Hyphenation patterns for Afrikaans",
+                version: Some("1.0 (September 2013)"),
+                copyright: Some("Copyright (C) 2013 Tilla Fick and Chris Swanepoel"),
+                authors: &["Tilla Fick", "Chris Swanepoel"],
+                licences: &[
+                    Licence {
+                        name: Some("LPPL"),
+                        version: Some("1.3"),
+                        or_later: true,
+                        url: None,
+                        text: None,
+                    },
+                ],
+            }),
+            #[cfg(feature = "albanian")]
+            Self::Albanian => Some(Metadata {
+                title: "Hyphenation patterns for Albanian",
+                version: Some("2025-01-10"),
+                copyright: Some("Copyright (C) 2020 Claudio Beccari, Sabina Koliqi (C) 2025 Arthur Rosendahl"),
+                authors: &["Claudio Beccari", "Sabina Koliqi", "Arthur Rosendahl"],
+                licences: &[
+                    Licence {
+                        name: Some("MIT"),
+                        version: None,
+                        or_later: false,
+                        url: Some("http://opensource.org/licenses/mit-license.php"),
+                        text: Some("Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions: The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software. THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE."),
+                    },
+                ],
+            }),
+            #[cfg(feature = "armenian")]
+            Self::Armenian => None,
+            #[cfg(feature = "assamese")]
+            Self::Assamese => Some(Metadata {
+                title: "Hyphenation patterns for Assamese",
+                version: Some("0.9.0 2016-01-16"),
+                copyright: Some("Copyright (C) 2016 Santhosh Thottingal"),
+                authors: &["Santhosh Thottingal"],
+                licences: &[
+                    Licence {
+                        name: Some("MIT"),
+                        version: None,
+                        or_later: false,
+                        url: Some("https://opensource.org/licenses/MIT"),
+                        text: Some("Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the \"Software\"), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions: The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software. THE SOFTWARE IS PROVIDED \"AS IS\", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE."),
+                    },
+                    Licence {
+                        name: Some("LGPL"),
+                        version: Some("3"),
+                        or_later: true,
+                        url: Some("http://www.gnu.org/licenses/lgpl.html"),
+                        text: None,
+                    },
+                    Licence {
+                        name: Some("GPL"),
+                        version: Some("3"),
+                        or_later: true,
+                        url: Some("http://www.gnu.org/licenses/gpl.html"),
+                        text: None,
+                    },
+                ],
+            }),
+            #[cfg(feature = "basque")]
+            Self::Basque => None,
+            #[cfg(feature = "belarusian")]
+            Self::Belarusian => Some(Metadata {
+                title: "Hyphenation patterns for Belarusian",
+                version: Some("1.01 (October 2016)"),
+                copyright: Some("Copyright (C) 2016 Maksim Salau"),
+                authors: &["Maksim Salau"],
+                licences: &[
+                    Licence {
+                        name: Some("MIT"),
+                        version: None,
+                        or_later: false,
+                        url: Some("https://opensource.org/licenses/MIT"),
+                        text: Some("Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the \"Software\"), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions: The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software. THE SOFTWARE IS PROVIDED \"AS IS\", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE."),
+                    },
+                ],
+            }),
+            #[cfg(feature = "bengali")]
+            Self::Bengali => Some(Metadata {
+                title: "Hyphenation patterns for Bengali",
+                version: Some("0.9.0 2016-01-16"),
+                copyright: Some("Copyright (C) 2016 Santhosh Thottingal"),
+                authors: &["Santhosh Thottingal"],
+                licences: &[
+                    Licence {
+                        name: Some("MIT"),
+                        version: None,
+                        or_later: false,
+                        url: Some("https://opensource.org/licenses/MIT"),
+                        text: Some("Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the \"Software\"), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions: The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software. THE SOFTWARE IS PROVIDED \"AS IS\", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE."),
+                    },
+                    Licence {
+                        name: Some("LGPL"),
+                        version: Some("3"),
+                        or_later: true,
+                        url: Some("http://www.gnu.org/licenses/lgpl.html"),
+                        text: None,
+                    },
+                    Licence {
+                        name: Some("GPL"),
+                        version: Some("3"),
+                        or_later: true,
+                        url: Some("http://www.gnu.org/licenses/gpl.html"),
+                        text: None,
+                    },
+                ],
+            }),
+            #[cfg(feature = "bulgarian")]
+            Self::Bulgarian => Some(Metadata {
+                title: "Hyphenation patterns for Bulgarian",
+                version: Some("21 October 2017"),
+                copyright: Some("Copyright (C) 2000, 2004, 2017 Anton Zinoviev"),
+                authors: &["Anton Zinoviev"],
+                licences: &[
+                    Licence {
+                        name: None,
+                        version: None,
+                        or_later: false,
+                        url: None,
+                        text: Some("This software may be used, modified, copied, distributed, and sold, both in source and binary form provided that the above copyright notice and these terms are retained. The name of the author may not be used to endorse or promote products derived from this software without prior permission.  THIS SOFTWARE IS PROVIDES \"AS IS\" AND ANY EXPRESS OR IMPLIED WARRANTIES ARE DISCLAIMED.  IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY DAMAGES ARISING IN ANY WAY OUT OF THE USE OF THIS SOFTWARE."),
+                    },
+                ],
+            }),
+            #[cfg(feature = "canadian-syllabics")]
+            Self::CanadianSyllabics => None,
+            #[cfg(feature = "catalan")]
+            Self::Catalan => None,
+            #[cfg(feature = "cherokee")]
+            Self::Cherokee => None,
+            #[cfg(feature = "church-slavonic")]
+            Self::ChurchSlavonic => None,
+            #[cfg(feature = "coptic")]
+            Self::Coptic => None,
+            #[cfg(feature = "croatian")]
+            Self::Croatian => Some(Metadata {
+                title: "Hyphenation patterns for Croatian",
+                version: None,
+                copyright: Some("Copyright (C) 1994, 1996, 2011, 2015 Igor Marinović"),
+                authors: &["Igor Marinović"],
+                licences: &[
+                    Licence {
+                        name: Some("LPPL"),
+                        version: Some("1"),
+                        or_later: true,
+                        url: Some("http://www.latex-project.org/lppl/lppl-1-0.html"),
+                        text: None,
+                    },
+                    Licence {
+                        name: None,
+                        version: None,
+                        or_later: false,
+                        url: None,
+                        text: Some("Permission is hereby granted, free of charge, to any person obtaining a copy of this file and any associated documentation (the \"Data Files\") to deal in the Data Files without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, and/or sell copies of the Data Files, and to permit persons to whom the Data Files are furnished to do so, provided that (a) this copyright and permission notice appear with all copies of the Data Files, (b) this copyright and permission notice appear in associated documentation, and (c) there is clear notice in each modified Data File as well as in the documentation associated with the Data File(s) that the data has been modified. THE DATA FILES ARE PROVIDED \"AS IS\", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT OF THIRD PARTY RIGHTS. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR HOLDERS INCLUDED IN THIS NOTICE BE LIABLE FOR ANY CLAIM, OR ANY SPECIAL INDIRECT OR CONSEQUENTIAL DAMAGES, OR ANY DAMAGES WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THE DATA FILES. Except as contained in this notice, the name of a copyright holder shall not be used in advertising or otherwise to promote the sale, use or other dealings in these Data Files without prior written authorization of the copyright holder."),
+                    },
+                ],
+            }),
+            #[cfg(feature = "czech")]
+            Self::Czech => None,
+            #[cfg(feature = "danish")]
+            Self::Danish => Some(Metadata {
+                title: "Hyphenation patterns for Danish",
+                version: Some("2011-01-11"),
+                copyright: Some("Copyright (C) 1994 Frank Jensen"),
+                authors: &["Frank Jensen"],
+                licences: &[
+                    Licence {
+                        name: Some("LPPL"),
+                        version: Some("1.3"),
+                        or_later: true,
+                        url: Some("http://www.latex-project.org/lppl/lppl-1-3.html"),
+                        text: None,
+                    },
+                    Licence {
+                        name: Some("MIT"),
+                        version: None,
+                        or_later: false,
+                        url: Some("https://opensource.org/licenses/MIT"),
+                        text: Some("Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the \"Software\"), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions: The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software. THE SOFTWARE IS PROVIDED \"AS IS\", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE."),
+                    },
+                ],
+            }),
+            #[cfg(feature = "dutch")]
+            Self::Dutch => Some(Metadata {
+                title: "Hyphenation patterns for Dutch",
+                version: Some("1.1 (November 1996)"),
+                copyright: Some("Copyright (C) 1996 Piet Tutelaers"),
+                authors: &["Piet Tutelaers"],
+                licences: &[
+                    Licence {
+                        name: Some("LPPL"),
+                        version: Some("1"),
+                        or_later: true,
+                        url: Some("https://latex-project.org/lppl/"),
+                        text: None,
+                    },
+                ],
+            }),
+            #[cfg(feature = "english")]
+            Self::English => Some(Metadata {
+                title: "Hyphenation patterns for American English",
+                version: Some("2005-05-30"),
+                copyright: Some("Copyright (C) 1990, 2004, 2005 Gerard D.C. Kuiken"),
+                authors: &["Gerard D.C. Kuiken"],
+                licences: &[
+                    Licence {
+                        name: None,
+                        version: None,
+                        or_later: false,
+                        url: None,
+                        text: Some("Copying and distribution of this file, with or without modification, are permitted in any medium without royalty provided the copyright notice and this notice are preserved."),
+                    },
+                ],
+            }),
+            #[cfg(feature = "esperanto")]
+            Self::Esperanto => None,
+            #[cfg(feature = "estonian")]
+            Self::Estonian => Some(Metadata {
+                title: "Hyphenation patterns for Estonian",
+                version: None,
+                copyright: Some("Copyright (C) 2004-2015 Enn Saar"),
+                authors: &["Enn Saar"],
+                licences: &[
+                    Licence {
+                        name: Some("MIT"),
+                        version: None,
+                        or_later: false,
+                        url: Some("https://opensource.org/licenses/MIT"),
+                        text: Some("Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the \"Software\"), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions: The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software. THE SOFTWARE IS PROVIDED \"AS IS\", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. \u{a0}IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE."),
+                    },
+                    Licence {
+                        name: Some("LPPL"),
+                        version: Some("1.3"),
+                        or_later: true,
+                        url: Some("http://www.latex-project.org/lppl.txt"),
+                        text: None,
+                    },
+                ],
+            }),
+            #[cfg(feature = "ethiopic")]
+            Self::Ethiopic => None,
+            #[cfg(feature = "finnish")]
+            Self::Finnish => Some(Metadata {
+                title: "Hyphenation patterns for Finnish",
+                version: Some("2.2"),
+                copyright: Some("Copyright (C) 1986, 1988, 1989 Kauko Saarinen"),
+                authors: &["Kauko Saarinen"],
+                licences: &[
+                    Licence {
+                        name: None,
+                        version: None,
+                        or_later: false,
+                        url: None,
+                        text: Some("Patterns may be freely distributed"),
+                    },
+                ],
+            }),
+            #[cfg(feature = "french")]
+            Self::French => Some(Metadata {
+                title: "Hyphenation patterns for French",
+                version: Some("V2.13 2016/05/12"),
+                copyright: Some("Copyright (C) 1994-2002 Daniel Flipo, Bernard Gaulle, 2016 Arthur Reutenauer"),
+                authors: &["Daniel Flipo", "Bernard Gaulle", "Arthur Reutenauer"],
+                licences: &[
+                    Licence {
+                        name: Some("MIT"),
+                        version: None,
+                        or_later: false,
+                        url: Some("https://opensource.org/licenses/MIT"),
+                        text: Some("Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the \"Software\"), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions: The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software. THE SOFTWARE IS PROVIDED \"AS IS\", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE."),
+                    },
+                ],
+            }),
+            #[cfg(feature = "friulian")]
+            Self::Friulian => None,
+            #[cfg(feature = "galician")]
+            Self::Galician => Some(Metadata {
+                title: "Hyphenation patterns for Galician",
+                version: Some("2.4 2010/04/23"),
+                copyright: Some("Copyright (C) 2006, 2007, 2008, 2010 Javier A. Múgica"),
+                authors: &["Javier A. Múgica"],
+                licences: &[
+                    Licence {
+                        name: Some("Unlicence"),
+                        version: None,
+                        or_later: false,
+                        url: Some("https://unlicense.org/"),
+                        text: None,
+                    },
+                ],
+            }),
+            #[cfg(feature = "georgian")]
+            Self::Georgian => Some(Metadata {
+                title: "Hyphenation patterns for Georgian",
+                version: Some("0.3 (April 2013)"),
+                copyright: Some("Copyright (C) 2013 Levan Shoshiashvili"),
+                authors: &["Levan Shoshiashvili"],
+                licences: &[
+                    Licence {
+                        name: Some("LPPL"),
+                        version: Some("1.3"),
+                        or_later: true,
+                        url: Some("http://www.latex-project.org/lppl.txt"),
+                        text: None,
+                    },
+                ],
+            }),
+            #[cfg(feature = "german")]
+            Self::German => Some(Metadata {
+                title: "German Hyphenation Patterns (Reformed Orthography, 2006)",
+                version: Some("2024-02-28"),
+                copyright: Some("Copyright (c) 2013-2024 Stephan Hennig, Werner Lemberg, Günter Milde, Sander van Geloven, Georg Pfeiffer, Gisbert W. Selke, Tobias Wendorf, Keno Wehr"),
+                authors: &["Deutschsprachige Trennmustermannschaft"],
+                licences: &[
+                    Licence {
+                        name: Some("MIT"),
+                        version: None,
+                        or_later: false,
+                        url: Some("https://opensource.org/licenses/mit-license.php"),
+                        text: Some("Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions: The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software. THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE."),
+                    },
+                ],
+            }),
+            #[cfg(feature = "greek")]
+            Self::Greek => Some(Metadata {
+                title: "Hyphenation patterns for Modern Greek, monotonic",
+                version: Some("5.0"),
+                copyright: Some("Copyright (C) 2008-2011 Dimitrios Filippou"),
+                authors: &["Dimitrios Filippou"],
+                licences: &[
+                    Licence {
+                        name: Some("LPPL"),
+                        version: None,
+                        or_later: false,
+                        url: Some("https://latex-project.org/lppl/"),
+                        text: None,
+                    },
+                ],
+            }),
+            #[cfg(feature = "gujarati")]
+            Self::Gujarati => Some(Metadata {
+                title: "Hyphenation patterns for Gujarati",
+                version: Some("0.9.0 2016-01-16"),
+                copyright: Some("Copyright (C) 2016 Santhosh Thottingal"),
+                authors: &["Santhosh Thottingal"],
+                licences: &[
+                    Licence {
+                        name: Some("MIT"),
+                        version: None,
+                        or_later: false,
+                        url: Some("https://opensource.org/licenses/MIT"),
+                        text: Some("Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the \"Software\"), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions: The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software. THE SOFTWARE IS PROVIDED \"AS IS\", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE."),
+                    },
+                    Licence {
+                        name: Some("LGPL"),
+                        version: Some("3"),
+                        or_later: true,
+                        url: Some("http://www.gnu.org/licenses/lgpl.html"),
+                        text: None,
+                    },
+                    Licence {
+                        name: Some("GPL"),
+                        version: Some("3"),
+                        or_later: true,
+                        url: Some("http://www.gnu.org/licenses/gpl.html"),
+                        text: None,
+                    },
+                ],
+            }),
+            #[cfg(feature = "hindi")]
+            Self::Hindi => Some(Metadata {
+                title: "Hyphenation patterns for Hindi",
+                version: Some("0.9.0 2016-01-16"),
+                copyright: Some("Copyright (C) 2016 Santhosh Thottingal"),
+                authors: &["Santhosh Thottingal"],
+                licences: &[
+                    Licence {
+                        name: Some("MIT"),
+                        version: None,
+                        or_later: false,
+                        url: Some("https://opensource.org/licenses/MIT"),
+                        text: Some("Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the \"Software\"), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions: The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software. THE SOFTWARE IS PROVIDED \"AS IS\", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE."),
+                    },
+                    Licence {
+                        name: Some("LGPL"),
+                        version: Some("3"),
+                        or_later: true,
+                        url: Some("http://www.gnu.org/licenses/lgpl.html"),
+                        text: None,
+                    },
+                    Licence {
+                        name: Some("GPL"),
+                        version: Some("3"),
+                        or_later: true,
+                        url: Some("http://www.gnu.org/licenses/gpl.html"),
+                        text: None,
+                    },
+                ],
+            }),
+            #[cfg(feature = "hungarian")]
+            Self::Hungarian => Some(Metadata {
+                title: "Hyphenation patterns for Hungarian",
+                version: Some("2011-08-15"),
+                copyright: Some("Copyright (C) 2003 Bence Nagy"),
+                authors: &["Bence Nagy"],
+                licences: &[
+                    Licence {
+                        name: Some("MPL"),
+                        version: Some("1.1"),
+                        or_later: false,
+                        url: Some("https://www.mozilla.org/en-US/MPL/1.1/"),
+                        text: None,
+                    },
+                    Licence {
+                        name: Some("GPL"),
+                        version: Some("2.0"),
+                        or_later: false,
+                        url: Some("http://www.gnu.org/licenses/old-licenses/gpl-2.0.html"),
+                        text: None,
+                    },
+                    Licence {
+                        name: Some("LGPL"),
+                        version: Some("2.1"),
+                        or_later: false,
+                        url: Some("http://www.gnu.org/licenses/old-licenses/lgpl-2.1.html"),
+                        text: None,
+                    },
+                ],
+            }),
+            #[cfg(feature = "icelandic")]
+            Self::Icelandic => Some(Metadata {
+                title: "Hyphenation patterns for Icelandic",
+                version: None,
+                copyright: Some("Copyright (C) 1988, 2004 Jörgen Pind"),
+                authors: &["Jörgen Pind"],
+                licences: &[
+                    Licence {
+                        name: Some("LPPL"),
+                        version: Some("1.2"),
+                        or_later: true,
+                        url: Some("http://www.latex-project.org/lppl.txt"),
+                        text: None,
+                    },
+                ],
+            }),
+            #[cfg(feature = "indonesian")]
+            Self::Indonesian => None,
+            #[cfg(feature = "interlingua")]
+            Self::Interlingua => None,
+            #[cfg(feature = "irish")]
+            Self::Irish => None,
+            #[cfg(feature = "italian")]
+            Self::Italian => Some(Metadata {
+                title: "Hyphenation patterns for Italian",
+                version: Some("4.9 2014/04/22"),
+                copyright: Some("Copyright (C) 2008-2011 Claudio Beccari"),
+                authors: &["Claudio Beccari"],
+                licences: &[
+                    Licence {
+                        name: Some("LPPL"),
+                        version: Some("1.3"),
+                        or_later: true,
+                        url: Some("http://www.latex-project.org/lppl.txt"),
+                        text: None,
+                    },
+                    Licence {
+                        name: Some("MIT"),
+                        version: None,
+                        or_later: false,
+                        url: Some("https://opensource.org/licenses/MIT"),
+                        text: Some("Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the \"Software\"), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions: The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software. THE SOFTWARE IS PROVIDED \"AS IS\", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE."),
+                    },
+                ],
+            }),
+            #[cfg(feature = "kannada")]
+            Self::Kannada => Some(Metadata {
+                title: "Hyphenation patterns for Kannada",
+                version: Some("0.9.0 2016-01-16"),
+                copyright: Some("Copyright (C) 2016 Santhosh Thottingal"),
+                authors: &["Santhosh Thottingal"],
+                licences: &[
+                    Licence {
+                        name: Some("MIT"),
+                        version: None,
+                        or_later: false,
+                        url: Some("https://opensource.org/licenses/MIT"),
+                        text: Some("Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the \"Software\"), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions: The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software. THE SOFTWARE IS PROVIDED \"AS IS\", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE."),
+                    },
+                    Licence {
+                        name: Some("LGPL"),
+                        version: Some("3"),
+                        or_later: true,
+                        url: Some("http://www.gnu.org/licenses/lgpl.html"),
+                        text: None,
+                    },
+                    Licence {
+                        name: Some("GPL"),
+                        version: Some("3"),
+                        or_later: true,
+                        url: Some("http://www.gnu.org/licenses/gpl.html"),
+                        text: None,
+                    },
+                ],
+            }),
+            #[cfg(feature = "khmer")]
+            Self::Khmer => None,
+            #[cfg(feature = "korean")]
+            Self::Korean => None,
+            #[cfg(feature = "kurmanji")]
+            Self::Kurmanji => Some(Metadata {
+                title: "Hyphenation patterns for Kurmanji (Northern Kurdish) – as spoken in Turkey and by the Kurdish diaspora in Europe",
+                version: Some("1.0"),
+                copyright: Some("Copyright (C) 2009 Jörg Knappen, Medeni Shemdê"),
+                authors: &["Jörg Knappen", "Medeni Shemdê"],
+                licences: &[
+                    Licence {
+                        name: Some("LPPL"),
+                        version: Some("1.3"),
+                        or_later: false,
+                        url: Some("https://latex-project.org/lppl/lppl-1-3.html"),
+                        text: None,
+                    },
+                ],
+            }),
+            #[cfg(feature = "lao")]
+            Self::Lao => None,
+            #[cfg(feature = "latin")]
+            Self::Latin => Some(Metadata {
+                title: "Hyphenation patterns for modern and medieval Latin",
+                version: Some("3.201 2016-08-28"),
+                copyright: Some("Copyright (C) 1999-2016 Claudio Beccari"),
+                authors: &["Claudio Beccari"],
+                licences: &[
+                    Licence {
+                        name: Some("MIT"),
+                        version: None,
+                        or_later: false,
+                        url: Some("https://opensource.org/licenses/MIT"),
+                        text: Some("Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions: The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software. THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE."),
+                    },
+                    Licence {
+                        name: Some("LPPL"),
+                        version: Some("1"),
+                        or_later: true,
+                        url: Some("https://latex-project.org/lppl/"),
+                        text: None,
+                    },
+                ],
+            }),
+            #[cfg(feature = "latin-classic")]
+            Self::LatinClassic => None,
+            #[cfg(feature = "latin-liturgical")]
+            Self::LatinLiturgical => None,
+            #[cfg(feature = "latvian")]
+            Self::Latvian => None,
+            #[cfg(feature = "lithuanian")]
+            Self::Lithuanian => Some(Metadata {
+                title: "Hyphenation patterns for Lithuanian",
+                version: None,
+                copyright: Some("Copyright (C) 1992 Sigitas Tolušis, Yannis Haralambous"),
+                authors: &["Vytas Statulevičius", "Sigitas Tolušis", "Yannis Haralambous"],
+                licences: &[
+                    Licence {
+                        name: Some("MIT"),
+                        version: None,
+                        or_later: false,
+                        url: Some("https://opensource.org/licenses/MIT"),
+                        text: Some("Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the \"Software\"), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions: The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software. THE SOFTWARE IS PROVIDED \"AS IS\", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. \u{a0}IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE."),
+                    },
+                ],
+            }),
+            #[cfg(feature = "malayalam")]
+            Self::Malayalam => Some(Metadata {
+                title: "Hyphenation patterns for Malayalam",
+                version: Some("0.9.0 2016-01-16"),
+                copyright: Some("Copyright (C) 2016 Santhosh Thottingal"),
+                authors: &["Santhosh Thottingal"],
+                licences: &[
+                    Licence {
+                        name: Some("MIT"),
+                        version: None,
+                        or_later: false,
+                        url: Some("https://opensource.org/licenses/MIT"),
+                        text: Some("Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the \"Software\"), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions: The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software. THE SOFTWARE IS PROVIDED \"AS IS\", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE."),
+                    },
+                    Licence {
+                        name: Some("LGPL"),
+                        version: Some("3"),
+                        or_later: true,
+                        url: Some("http://www.gnu.org/licenses/lgpl.html"),
+                        text: None,
+                    },
+                    Licence {
+                        name: Some("GPL"),
+                        version: Some("3"),
+                        or_later: true,
+                        url: Some("http://www.gnu.org/licenses/gpl.html"),
+                        text: None,
+                    },
+                ],
+            }),
+            #[cfg(feature = "marathi")]
+            Self::Marathi => Some(Metadata {
+                title: "Hyphenation patterns for Marathi",
+                version: Some("0.9.0 2016-01-16"),
+                copyright: Some("Copyright (C) 2016 Santhosh Thottingal"),
+                authors: &["Santhosh Thottingal"],
+                licences: &[
+                    Licence {
+                        name: Some("MIT"),
+                        version: None,
+                        or_later: false,
+                        url: Some("https://opensource.org/licenses/MIT"),
+                        text: Some("Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the \"Software\"), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions: The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software. THE SOFTWARE IS PROVIDED \"AS IS\", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE."),
+                    },
+                    Licence {
+                        name: Some("LGPL"),
+                        version: Some("3"),
+                        or_later: true,
+                        url: Some("http://www.gnu.org/licenses/lgpl.html"),
+                        text: None,
+                    },
+                    Licence {
+                        name: Some("GPL"),
+                        version: Some("3"),
+                        or_later: true,
+                        url: Some("http://www.gnu.org/licenses/gpl.html"),
+                        text: None,
+                    },
+                ],
+            }),
+            #[cfg(feature = "mongolian")]
+            Self::Mongolian => Some(Metadata {
+                title: "Hyphenation patterns for Mongolian, Cyrillic script",
+                version: Some("v1.2 2008/03/23"),
+                copyright: Some("Copyright (C) 2007-2015 by Dorjgotov Batmunkh"),
+                authors: &["Dorjgotov Batmunkh"],
+                licences: &[
+                    Licence {
+                        name: Some("LPPL"),
+                        version: Some("1.3"),
+                        or_later: true,
+                        url: Some("http://www.latex-project.org/lppl/lppl-1-3.txt"),
+                        text: None,
+                    },
+                    Licence {
+                        name: Some("MIT"),
+                        version: None,
+                        or_later: false,
+                        url: Some("https://opensource.org/licenses/MIT"),
+                        text: Some("Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the \"Software\"), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions: The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software. THE SOFTWARE IS PROVIDED \"AS IS\", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE."),
+                    },
+                ],
+            }),
+            #[cfg(feature = "norwegian")]
+            Self::Norwegian => Some(Metadata {
+                title: "Hyphenation patterns for Norwegian",
+                version: Some("2012-05-18 Patterns of January 25, 2005, with later additions and changes"),
+                copyright: Some("Copyright (C) 2004, 2005 Rune Kleveland, Ole Michael Selberg"),
+                authors: &["Rune Kleveland", "Ole Michael Selberg"],
+                licences: &[
+                    Licence {
+                        name: None,
+                        version: None,
+                        or_later: false,
+                        url: None,
+                        text: Some("Copying and distribution of this file, with or without modification, is permitted in any medium without royalty provided the copyright notice and this notice are preserved."),
+                    },
+                ],
+            }),
+            #[cfg(feature = "norwegian-bokmal")]
+            Self::NorwegianBokmal => Some(Metadata {
+                title: "Hyphenation patterns for Norwegian",
+                version: Some("2012-05-18 Patterns of January 25, 2005, with later additions and changes"),
+                copyright: Some("Copyright (C) 2004, 2005 Rune Kleveland, Ole Michael Selberg"),
+                authors: &["Rune Kleveland", "Ole Michael Selberg"],
+                licences: &[
+                    Licence {
+                        name: None,
+                        version: None,
+                        or_later: false,
+                        url: None,
+                        text: Some("Copying and distribution of this file, with or without modification, is permitted in any medium without royalty provided the copyright notice and this notice are preserved."),
+                    },
+                ],
+            }),
+            #[cfg(feature = "norwegian-nynorsk")]
+            Self::NorwegianNynorsk => Some(Metadata {
+                title: "Hyphenation patterns for Norwegian",
+                version: Some("2012-05-18 Patterns of January 25, 2005, with later additions and changes"),
+                copyright: Some("Copyright (C) 2004, 2005 Rune Kleveland, Ole Michael Selberg"),
+                authors: &["Rune Kleveland", "Ole Michael Selberg"],
+                licences: &[
+                    Licence {
+                        name: None,
+                        version: None,
+                        or_later: false,
+                        url: None,
+                        text: Some("Copying and distribution of this file, with or without modification, is permitted in any medium without royalty provided the copyright notice and this notice are preserved."),
+                    },
+                ],
+            }),
+            #[cfg(feature = "occitan")]
+            Self::Occitan => None,
+            #[cfg(feature = "oriya")]
+            Self::Oriya => Some(Metadata {
+                title: "Hyphenation patterns for Oriya",
+                version: Some("0.9.0 2016-01-16"),
+                copyright: Some("Copyright (C) 2016 Santhosh Thottingal"),
+                authors: &["Santhosh Thottingal"],
+                licences: &[
+                    Licence {
+                        name: Some("MIT"),
+                        version: None,
+                        or_later: false,
+                        url: Some("https://opensource.org/licenses/MIT"),
+                        text: Some("Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions: The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software. THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE."),
+                    },
+                    Licence {
+                        name: Some("LGPL"),
+                        version: Some("3"),
+                        or_later: true,
+                        url: Some("http://www.gnu.org/licenses/lgpl.html"),
+                        text: None,
+                    },
+                    Licence {
+                        name: Some("GPL"),
+                        version: Some("3"),
+                        or_later: true,
+                        url: Some("http://www.gnu.org/licenses/gpl.html"),
+                        text: None,
+                    },
+                ],
+            }),
+            #[cfg(feature = "pali")]
+            Self::Pali => None,
+            #[cfg(feature = "panjabi")]
+            Self::Panjabi => Some(Metadata {
+                title: "Hyphenation patterns for Panjabi",
+                version: Some("0.9.0 2016-01-16"),
+                copyright: Some("Copyright (C) 2016 Santhosh Thottingal"),
+                authors: &["Santhosh Thottingal"],
+                licences: &[
+                    Licence {
+                        name: Some("MIT"),
+                        version: None,
+                        or_later: false,
+                        url: Some("https://opensource.org/licenses/MIT"),
+                        text: Some("Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions: The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software. THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE."),
+                    },
+                    Licence {
+                        name: Some("LGPL"),
+                        version: Some("3"),
+                        or_later: true,
+                        url: Some("http://www.gnu.org/licenses/lgpl.html"),
+                        text: None,
+                    },
+                    Licence {
+                        name: Some("GPL"),
+                        version: Some("3"),
+                        or_later: true,
+                        url: Some("http://www.gnu.org/licenses/gpl.html"),
+                        text: None,
+                    },
+                ],
+            }),
+            #[cfg(feature = "piedmontese")]
+            Self::Piedmontese => None,
+            #[cfg(feature = "polish")]
+            Self::Polish => Some(Metadata {
+                title: "Hyphenation patterns for Polish",
+                version: Some("3.0a, Wednesday, May 17th, 1995"),
+                copyright: Some("Copyright (C) 1987-1995 Hanna Kołodziejska, Bogusław Jackowski, Marek Ryćko"),
+                authors: &["Hanna Kołodziejska", "Bogusław Jackowski", "Marek Ryćko"],
+                licences: &[
+                    Licence {
+                        name: Some("MIT"),
+                        version: None,
+                        or_later: false,
+                        url: Some("https://opensource.org/licenses/MIT"),
+                        text: Some("Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the “Software”), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions: The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software. THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE."),
+                    },
+                    Licence {
+                        name: None,
+                        version: None,
+                        or_later: false,
+                        url: None,
+                        text: Some("This macro file belongs to the public domain under the conditions specified by the author of TeX: “Macro files like PLAIN.TEX should not be changed in any way, except with respect to preloaded fonts, unless the changes are authorized by the authors of the macros.” Donald E. Knuth"),
+                    },
+                ],
+            }),
+            #[cfg(feature = "portuguese")]
+            Self::Portuguese => Some(Metadata {
+                title: "Hyphenation patterns for Portuguese",
+                version: Some("1.4 2024-07-13"),
+                copyright: Some("Copyright (C) 1987, 1994, 1996, 2015 Pedro J. de Rezende, 1996, 2015 J. Joao Dias Almeida, 2024 Leonardo Araujo and Aline Benevides"),
+                authors: &["Pedro J. de Rezende", "J. Joao Dias Almeida", "Leonardo Araujo", "Aline Benevides"],
+                licences: &[
+                    Licence {
+                        name: Some("BSD 3-clause licence"),
+                        version: None,
+                        or_later: false,
+                        url: Some("https://opensource.org/licenses/BSD-3-Clause"),
+                        text: Some("Redistribution and use in source and binary forms, with or without modification, are permitted provided that the following conditions are met: * Redistributions of source code must retain the above copyright notice, this list of conditions and the following disclaimer. * Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the following disclaimer in the documentation and/or other materials provided with the distribution. * Neither the name of the University of Campinas, of the University of Minho nor the names of its contributors may be used to endorse or promote products derived from this software without specific prior written permission. THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS \"AS IS\" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL PEDRO J. DE REZENDE OR J.JOAO DIAS ALMEIDA BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE."),
+                    },
+                ],
+            }),
+            #[cfg(feature = "romanian")]
+            Self::Romanian => None,
+            #[cfg(feature = "romansh")]
+            Self::Romansh => None,
+            #[cfg(feature = "russian")]
+            Self::Russian => Some(Metadata {
+                title: "Hyphenation patterns for Russian",
+                version: Some("2003/03/10"),
+                copyright: Some("Copyright (C) 1999-2003 Alexander I. Lebedev"),
+                authors: &["Alexander I. Lebedev"],
+                licences: &[
+                    Licence {
+                        name: Some("LPPL"),
+                        version: Some("1.2"),
+                        or_later: true,
+                        url: Some("https://latex-project.org/lppl/"),
+                        text: None,
+                    },
+                ],
+            }),
+            #[cfg(feature = "sanskrit")]
+            Self::Sanskrit => Some(Metadata {
+                title: "Hyphenation patterns for Sanskrit and Prakrit, Latin transliteration, and Devanāgari, Bengali, Gujarati, Kannada, Malayalam, and Telugu scripts",
+                version: Some("0.6"),
+                copyright: Some("Copyright (C) 2006-2011 Yves Codet"),
+                authors: &["Yves Codet"],
+                licences: &[
+                    Licence {
+                        name: None,
+                        version: None,
+                        or_later: false,
+                        url: None,
+                        text: Some("You may freely use, copy, modify and/or distribute this file."),
+                    },
+                ],
+            }),
+            #[cfg(feature = "serbian")]
+            Self::Serbian => Some(Metadata {
+                title: "Hyphenation patterns for Serbo-Croatian, Cyrillic script",
+                version: Some("2.02 Jun 22, 2008"),
+                copyright: Some("Copyright (C) 1990, 2008 Dejan Muhamedagić"),
+                authors: &["Dejan Muhamedagić"],
+                licences: &[
+                    Licence {
+                        name: Some("LPPL"),
+                        version: Some("1"),
+                        or_later: true,
+                        url: Some("https://latex-project.org/lppl/"),
+                        text: None,
+                    },
+                ],
+            }),
+            #[cfg(feature = "serbian-latin")]
+            Self::SerbianLatin => Some(Metadata {
+                title: "Hyphenation patterns for Serbo-Croatian, Cyrillic script",
+                version: Some("2.02 Jun 22, 2008"),
+                copyright: Some("Copyright (C) 1990, 2008 Dejan Muhamedagić"),
+                authors: &["Dejan Muhamedagić"],
+                licences: &[
+                    Licence {
+                        name: Some("LPPL"),
+                        version: Some("1"),
+                        or_later: true,
+                        url: Some("https://latex-project.org/lppl/"),
+                        text: None,
+                    },
+                ],
+            }),
+            #[cfg(feature = "slovak")]
+            Self::Slovak => Some(Metadata {
+                title: "Hyphenation patterns for Slovak",
+                version: Some("2.0 1992-04-24"),
+                copyright: Some("Copyright (C) 1992 Jana Chlebíková"),
+                authors: &["Jana Chlebíková"],
+                licences: &[
+                    Licence {
+                        name: Some("GPL"),
+                        version: Some("2"),
+                        or_later: true,
+                        url: Some("http://www.gnu.org/licenses/gpl.html"),
+                        text: None,
+                    },
+                ],
+            }),
+            #[cfg(feature = "slovenian")]
+            Self::Slovenian => Some(Metadata {
+                title: "Hyphenation patterns for Slovenian",
+                version: Some("2.3"),
+                copyright: Some("Copyright (C) 1990 Matjaž Vrečko"),
+                authors: &["Matjaž Vrečko"],
+                licences: &[
+                    Licence {
+                        name: Some("LPPL"),
+                        version: Some("1"),
+                        or_later: true,
+                        url: Some("http://www.latex-project.org/lppl/lppl-1-0.html"),
+                        text: None,
+                    },
+                    Licence {
+                        name: Some("MIT"),
+                        version: None,
+                        or_later: false,
+                        url: Some("https://opensource.org/licences/MIT"),
+                        text: Some("Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the \"Software\"), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions: The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software. THE SOFTWARE IS PROVIDED \"AS IS\", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE."),
+                    },
+                ],
+            }),
+            #[cfg(feature = "spanish")]
+            Self::Spanish => Some(Metadata {
+                title: "Hyphenation patterns for Spanish",
+                version: Some("5.0 2019-09-24"),
+                copyright: Some("Copyright (C) 1993, 1997 Javier Bezos, 2001-2019 Javier Bezos, CervanTeX"),
+                authors: &["Javier Bezos"],
+                licences: &[
+                    Licence {
+                        name: Some("MIT/X11"),
+                        version: None,
+                        or_later: false,
+                        url: Some("https://opensource.org/licenses/MIT"),
+                        text: Some("Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the \"Software\"), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions: The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software. THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE."),
+                    },
+                ],
+            }),
+            #[cfg(feature = "swedish")]
+            Self::Swedish => Some(Metadata {
+                title: "Hyphenation patterns for Swedish",
+                version: Some("1994-03-03"),
+                copyright: Some("Copyright (C) 1994 Jan Michael Rynning"),
+                authors: &["Jan Michael Rynning"],
+                licences: &[
+                    Licence {
+                        name: Some("LPPL"),
+                        version: Some("1.2"),
+                        or_later: true,
+                        url: None,
+                        text: None,
+                    },
+                ],
+            }),
+            #[cfg(feature = "tamil")]
+            Self::Tamil => Some(Metadata {
+                title: "Hyphenation patterns for Tamil",
+                version: Some("0.9.0 2016-01-16"),
+                copyright: Some("Copyright (C) 2016 Santhosh Thottingal"),
+                authors: &["Santhosh Thottingal"],
+                licences: &[
+                    Licence {
+                        name: Some("MIT"),
+                        version: None,
+                        or_later: false,
+                        url: Some("https://opensource.org/licenses/MIT"),
+                        text: Some("Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the \"Software\"), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions: The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software. THE SOFTWARE IS PROVIDED \"AS IS\", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE."),
+                    },
+                    Licence {
+                        name: Some("LGPL"),
+                        version: Some("3"),
+                        or_later: true,
+                        url: Some("http://www.gnu.org/licenses/lgpl.html"),
+                        text: None,
+                    },
+                    Licence {
+                        name: Some("GPL"),
+                        version: Some("3"),
+                        or_later: true,
+                        url: Some("http://www.gnu.org/licenses/gpl.html"),
+                        text: None,
+                    },
+                ],
+            }),
+            #[cfg(feature = "telugu")]
+            Self::Telugu => Some(Metadata {
+                title: "Hyphenation patterns for Telugu",
+                version: Some("0.9.0 2016-01-16"),
+                copyright: Some("Copyright (C) 2016 Santhosh Thottingal"),
+                authors: &["Santhosh Thottingal"],
+                licences: &[
+                    Licence {
+                        name: Some("MIT"),
+                        version: None,
+                        or_later: false,
+                        url: Some("https://opensource.org/licenses/MIT"),
+                        text: Some("Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the \"Software\"), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions: The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software. THE SOFTWARE IS PROVIDED \"AS IS\", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE."),
+                    },
+                    Licence {
+                        name: Some("LGPL"),
+                        version: Some("3"),
+                        or_later: true,
+                        url: Some("http://www.gnu.org/licenses/lgpl.html"),
+                        text: None,
+                    },
+                    Licence {
+                        name: Some("GPL"),
+                        version: Some("3"),
+                        or_later: true,
+                        url: Some("http://www.gnu.org/licenses/gpl.html"),
+                        text: None,
+                    },
+                ],
+            }),
+            #[cfg(feature = "thai")]
+            Self::Thai => None,
+            #[cfg(feature = "turkish")]
+            Self::Turkish => Some(Metadata {
+                title: "Hyphenation patterns for Turkish",
+                version: None,
+                copyright: Some("Copyright (C) 1987 Pierre A. MacKay, 2008, 2011 TUG"),
+                authors: &["Pierre A. MacKay", "H. Turgut Uyar", "S. Ekin Kocabas", "Mojca Miklavec"],
+                licences: &[
+                    Licence {
+                        name: Some("LPPL"),
+                        version: Some("1"),
+                        or_later: true,
+                        url: Some("https://latex-project.org/lppl/lppl-1-0.html"),
+                        text: None,
+                    },
+                ],
+            }),
+            #[cfg(feature = "turkmen")]
+            Self::Turkmen => Some(Metadata {
+                title: "Hyphenation patterns for Turkmen",
+                version: Some("0.1 16 March 2010"),
+                copyright: Some("Copyright (C) 2010-2015 Nazar Annagurban"),
+                authors: &["Nazar Annagurban"],
+                licences: &[
+                    Licence {
+                        name: Some("MIT"),
+                        version: None,
+                        or_later: false,
+                        url: Some("https://opensource.org/licenses/MIT"),
+                        text: Some("Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the \"Software\"), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions: The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software. THE SOFTWARE IS PROVIDED \"AS IS\", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. \u{a0}IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE."),
+                    },
+                ],
+            }),
+            #[cfg(feature = "ukrainian")]
+            Self::Ukrainian => Some(Metadata {
+                title: "Hyphenation patterns for Ukrainian",
+                version: Some("2001/05/10"),
+                copyright: Some("Copyright (C) 1998-2001 Maksym Polyakov"),
+                authors: &["Maksym Polyakov"],
+                licences: &[
+                    Licence {
+                        name: Some("LPPL"),
+                        version: None,
+                        or_later: false,
+                        url: Some("https://latex-project.org/lppl/"),
+                        text: None,
+                    },
+                ],
+            }),
+            #[cfg(feature = "upper-sorbian")]
+            Self::UpperSorbian => None,
+            #[cfg(feature = "welsh")]
+            Self::Welsh => None,
+        }
+    }
+
+    /// The hyphen character to insert when breaking a
+    /// word in this language.
+    ///
+    /// This is `-` unless the language's script has a
+    /// dedicated hyphen, like `֊` for Armenian. Returns
+    /// `None` for languages that break without inserting
+    /// anything, like Thai or Korean.
+    pub fn hyphen(self) -> Option<char> {
+        match self {
+            #[cfg(feature = "afrikaans")]
+            Self::Afrikaans => Some('-'),
+            #[cfg(feature = "albanian")]
+            Self::Albanian => Some('-'),
+            #[cfg(feature = "armenian")]
+            Self::Armenian => Some('֊'),
+            #[cfg(feature = "assamese")]
+            Self::Assamese => Some('-'),
+            #[cfg(feature = "basque")]
+            Self::Basque => Some('-'),
+            #[cfg(feature = "belarusian")]
+            Self::Belarusian => Some('-'),
+            #[cfg(feature = "bengali")]
+            Self::Bengali => Some('-'),
+            #[cfg(feature = "bulgarian")]
+            Self::Bulgarian => Some('-'),
+            #[cfg(feature = "canadian-syllabics")]
+            Self::CanadianSyllabics => Some('-'),
+            #[cfg(feature = "catalan")]
+            Self::Catalan => Some('-'),
+            #[cfg(feature = "cherokee")]
+            Self::Cherokee => Some('-'),
+            #[cfg(feature = "church-slavonic")]
+            Self::ChurchSlavonic => Some('-'),
+            #[cfg(feature = "coptic")]
+            Self::Coptic => Some('-'),
+            #[cfg(feature = "croatian")]
+            Self::Croatian => Some('-'),
+            #[cfg(feature = "czech")]
+            Self::Czech => Some('-'),
+            #[cfg(feature = "danish")]
+            Self::Danish => Some('-'),
+            #[cfg(feature = "dutch")]
+            Self::Dutch => Some('-'),
+            #[cfg(feature = "english")]
+            Self::English => Some('-'),
+            #[cfg(feature = "esperanto")]
+            Self::Esperanto => Some('-'),
+            #[cfg(feature = "estonian")]
+            Self::Estonian => Some('-'),
+            #[cfg(feature = "ethiopic")]
+            Self::Ethiopic => Some('-'),
+            #[cfg(feature = "finnish")]
+            Self::Finnish => Some('-'),
+            #[cfg(feature = "french")]
+            Self::French => Some('-'),
+            #[cfg(feature = "friulian")]
+            Self::Friulian => Some('-'),
+            #[cfg(feature = "galician")]
+            Self::Galician => Some('-'),
+            #[cfg(feature = "georgian")]
+            Self::Georgian => Some('-'),
+            #[cfg(feature = "german")]
+            Self::German => Some('-'),
+            #[cfg(feature = "greek")]
+            Self::Greek => Some('-'),
+            #[cfg(feature = "gujarati")]
+            Self::Gujarati => Some('-'),
+            #[cfg(feature = "hindi")]
+            Self::Hindi => Some('-'),
+            #[cfg(feature = "hungarian")]
+            Self::Hungarian => Some('-'),
+            #[cfg(feature = "icelandic")]
+            Self::Icelandic => Some('-'),
+            #[cfg(feature = "indonesian")]
+            Self::Indonesian => Some('-'),
+            #[cfg(feature = "interlingua")]
+            Self::Interlingua => Some('-'),
+            #[cfg(feature = "irish")]
+            Self::Irish => Some('-'),
+            #[cfg(feature = "italian")]
+            Self::Italian => Some('-'),
+            #[cfg(feature = "kannada")]
+            Self::Kannada => Some('-'),
+            #[cfg(feature = "khmer")]
+            Self::Khmer => None,
+            #[cfg(feature = "korean")]
+            Self::Korean => None,
+            #[cfg(feature = "kurmanji")]
+            Self::Kurmanji => Some('-'),
+            #[cfg(feature = "lao")]
+            Self::Lao => None,
+            #[cfg(feature = "latin")]
+            Self::Latin => Some('-'),
+            #[cfg(feature = "latin-classic")]
+            Self::LatinClassic => Some('-'),
+            #[cfg(feature = "latin-liturgical")]
+            Self::LatinLiturgical => Some('-'),
+            #[cfg(feature = "latvian")]
+            Self::Latvian => Some('-'),
+            #[cfg(feature = "lithuanian")]
+            Self::Lithuanian => Some('-'),
+            #[cfg(feature = "malayalam")]
+            Self::Malayalam => Some('-'),
+            #[cfg(feature = "marathi")]
+            Self::Marathi => Some('-'),
+            #[cfg(feature = "mongolian")]
+            Self::Mongolian => Some('-'),
+            #[cfg(feature = "norwegian")]
+            Self::Norwegian => Some('-'),
+            #[cfg(feature = "norwegian-bokmal")]
+            Self::NorwegianBokmal => Some('-'),
+            #[cfg(feature = "norwegian-nynorsk")]
+            Self::NorwegianNynorsk => Some('-'),
+            #[cfg(feature = "occitan")]
+            Self::Occitan => Some('-'),
+            #[cfg(feature = "oriya")]
+            Self::Oriya => Some('-'),
+            #[cfg(feature = "pali")]
+            Self::Pali => Some('-'),
+            #[cfg(feature = "panjabi")]
+            Self::Panjabi => Some('-'),
+            #[cfg(feature = "piedmontese")]
+            Self::Piedmontese => Some('-'),
+            #[cfg(feature = "polish")]
+            Self::Polish => Some('-'),
+            #[cfg(feature = "portuguese")]
+            Self::Portuguese => Some('-'),
+            #[cfg(feature = "romanian")]
+            Self::Romanian => Some('-'),
+            #[cfg(feature = "romansh")]
+            Self::Romansh => Some('-'),
+            #[cfg(feature = "russian")]
+            Self::Russian => Some('-'),
+            #[cfg(feature = "sanskrit")]
+            Self::Sanskrit => Some('-'),
+            #[cfg(feature = "serbian")]
+            Self::Serbian => Some('-'),
+            #[cfg(feature = "serbian-latin")]
+            Self::SerbianLatin => Some('-'),
+            #[cfg(feature = "slovak")]
+            Self::Slovak => Some('-'),
+            #[cfg(feature = "slovenian")]
+            Self::Slovenian => Some('-'),
+            #[cfg(feature = "spanish")]
+            Self::Spanish => Some('-'),
+            #[cfg(feature = "swedish")]
+            Self::Swedish => Some('-'),
+            #[cfg(feature = "tamil")]
+            Self::Tamil => Some('-'),
+            #[cfg(feature = "telugu")]
+            Self::Telugu => Some('-'),
+            #[cfg(feature = "thai")]
+            Self::Thai => None,
+            #[cfg(feature = "turkish")]
+            Self::Turkish => Some('-'),
+            #[cfg(feature = "turkmen")]
+            Self::Turkmen => Some('-'),
+            #[cfg(feature = "ukrainian")]
+            Self::Ukrainian => Some('-'),
+            #[cfg(feature = "upper-sorbian")]
+            Self::UpperSorbian => Some('-'),
+            #[cfg(feature = "welsh")]
+            Self::Welsh => Some('-'),
         }
     }
 
-    fn root(self) -> State<'static> {
+    fn engine(self) -> Engine {
         match self {
             #[cfg(feature = "afrikaans")]
-            Self::Afrikaans => State::root(include_bytes!("../tries/af.bin")),
+            Self::Afrikaans => Engine::Patterns(State::root(include_bytes!("../tries/af.bin"))),
             #[cfg(feature = "albanian")]
-            Self::Albanian => State::root(include_bytes!("../tries/sq.bin")),
+            Self::Albanian => Engine::Patterns(State::root(include_bytes!("../tries/sq.bin"))),
+            #[cfg(feature = "armenian")]
+            Self::Armenian => Engine::Patterns(State::root(include_bytes!("../tries/hy.bin"))),
             #[cfg(feature = "assamese")]
-            Self::Assamese => State::root(include_bytes!("../tries/as.bin")),
+            Self::Assamese => Engine::Patterns(State::root(include_bytes!("../tries/as.bin"))),
+            #[cfg(feature = "basque")]
+            Self::Basque => Engine::Patterns(State::root(include_bytes!("../tries/eu.bin"))),
             #[cfg(feature = "belarusian")]
-            Self::Belarusian => State::root(include_bytes!("../tries/be.bin")),
+            Self::Belarusian => Engine::Patterns(State::root(include_bytes!("../tries/be.bin"))),
             #[cfg(feature = "bengali")]
-            Self::Bengali => State::root(include_bytes!("../tries/bn.bin")),
+            Self::Bengali => Engine::Patterns(State::root(include_bytes!("../tries/bn.bin"))),
             #[cfg(feature = "bulgarian")]
-            Self::Bulgarian => State::root(include_bytes!("../tries/bg.bin")),
+            Self::Bulgarian => Engine::Patterns(State::root(include_bytes!("../tries/bg.bin"))),
+            #[cfg(feature = "canadian-syllabics")]
+            Self::CanadianSyllabics => Engine::Syllabic,
             #[cfg(feature = "catalan")]
-            Self::Catalan => State::root(include_bytes!("../tries/ca.bin")),
+            Self::Catalan => Engine::Patterns(State::root(include_bytes!("../tries/ca.bin"))),
+            #[cfg(feature = "cherokee")]
+            Self::Cherokee => Engine::Syllabic,
+            #[cfg(feature = "church-slavonic")]
+            Self::ChurchSlavonic => Engine::Patterns(State::root(include_bytes!("../tries/cu.bin"))),
+            #[cfg(feature = "coptic")]
+            Self::Coptic => Engine::Patterns(State::root(include_bytes!("../tries/cop.bin"))),
             #[cfg(feature = "croatian")]
-            Self::Croatian => State::root(include_bytes!("../tries/hr.bin")),
+            Self::Croatian => Engine::Patterns(State::root(include_bytes!("../tries/hr.bin"))),
             #[cfg(feature = "czech")]
-            Self::Czech => State::root(include_bytes!("../tries/cs.bin")),
+            Self::Czech => Engine::Patterns(State::root(include_bytes!("../tries/cs.bin"))),
             #[cfg(feature = "danish")]
-            Self::Danish => State::root(include_bytes!("../tries/da.bin")),
+            Self::Danish => Engine::Patterns(State::root(include_bytes!("../tries/da.bin"))),
             #[cfg(feature = "dutch")]
-            Self::Dutch => State::root(include_bytes!("../tries/nl.bin")),
+            Self::Dutch => Engine::Patterns(State::root(include_bytes!("../tries/nl.bin"))),
             #[cfg(feature = "english")]
-            Self::English => State::root(include_bytes!("../tries/en.bin")),
+            Self::English => Engine::Patterns(State::root(include_bytes!("../tries/en.bin"))),
+            #[cfg(feature = "esperanto")]
+            Self::Esperanto => Engine::Patterns(State::root(include_bytes!("../tries/eo.bin"))),
             #[cfg(feature = "estonian")]
-            Self::Estonian => State::root(include_bytes!("../tries/et.bin")),
+            Self::Estonian => Engine::Patterns(State::root(include_bytes!("../tries/et.bin"))),
+            #[cfg(feature = "ethiopic")]
+            Self::Ethiopic => Engine::Syllabic,
             #[cfg(feature = "finnish")]
-            Self::Finnish => State::root(include_bytes!("../tries/fi.bin")),
+            Self::Finnish => Engine::Patterns(State::root(include_bytes!("../tries/fi.bin"))),
             #[cfg(feature = "french")]
-            Self::French => State::root(include_bytes!("../tries/fr.bin")),
+            Self::French => Engine::Patterns(State::root(include_bytes!("../tries/fr.bin"))),
+            #[cfg(feature = "friulian")]
+            Self::Friulian => Engine::Patterns(State::root(include_bytes!("../tries/fur.bin"))),
             #[cfg(feature = "galician")]
-            Self::Galician => State::root(include_bytes!("../tries/gl.bin")),
+            Self::Galician => Engine::Patterns(State::root(include_bytes!("../tries/gl.bin"))),
             #[cfg(feature = "georgian")]
-            Self::Georgian => State::root(include_bytes!("../tries/ka.bin")),
+            Self::Georgian => Engine::Patterns(State::root(include_bytes!("../tries/ka.bin"))),
             #[cfg(feature = "german")]
-            Self::German => State::root(include_bytes!("../tries/de.bin")),
+            Self::German => Engine::Patterns(State::root(include_bytes!("../tries/de.bin"))),
             #[cfg(feature = "greek")]
-            Self::Greek => State::root(include_bytes!("../tries/el.bin")),
+            Self::Greek => Engine::Patterns(State::root(include_bytes!("../tries/el.bin"))),
             #[cfg(feature = "gujarati")]
-            Self::Gujarati => State::root(include_bytes!("../tries/gu.bin")),
+            Self::Gujarati => Engine::Patterns(State::root(include_bytes!("../tries/gu.bin"))),
             #[cfg(feature = "hindi")]
-            Self::Hindi => State::root(include_bytes!("../tries/hi.bin")),
+            Self::Hindi => Engine::Patterns(State::root(include_bytes!("../tries/hi.bin"))),
             #[cfg(feature = "hungarian")]
-            Self::Hungarian => State::root(include_bytes!("../tries/hu.bin")),
+            Self::Hungarian => Engine::Patterns(State::root(include_bytes!("../tries/hu.bin"))),
             #[cfg(feature = "icelandic")]
-            Self::Icelandic => State::root(include_bytes!("../tries/is.bin")),
+            Self::Icelandic => Engine::Patterns(State::root(include_bytes!("../tries/is.bin"))),
+            #[cfg(feature = "indonesian")]
+            Self::Indonesian => Engine::Patterns(State::root(include_bytes!("../tries/id.bin"))),
+            #[cfg(feature = "interlingua")]
+            Self::Interlingua => Engine::Patterns(State::root(include_bytes!("../tries/ia.bin"))),
+            #[cfg(feature = "irish")]
+            Self::Irish => Engine::Patterns(State::root(include_bytes!("../tries/ga.bin"))),
             #[cfg(feature = "italian")]
-            Self::Italian => State::root(include_bytes!("../tries/it.bin")),
+            Self::Italian => Engine::Patterns(State::root(include_bytes!("../tries/it.bin"))),
             #[cfg(feature = "kannada")]
-            Self::Kannada => State::root(include_bytes!("../tries/kn.bin")),
+            Self::Kannada => Engine::Patterns(State::root(include_bytes!("../tries/kn.bin"))),
+            #[cfg(feature = "khmer")]
+            Self::Khmer => Engine::Dictionary(State::root(include_bytes!("../tries/km.bin"))),
+            #[cfg(feature = "korean")]
+            Self::Korean => Engine::Syllabic,
             #[cfg(feature = "kurmanji")]
-            Self::Kurmanji => State::root(include_bytes!("../tries/ku.bin")),
+            Self::Kurmanji => Engine::Patterns(State::root(include_bytes!("../tries/ku.bin"))),
+            #[cfg(feature = "lao")]
+            Self::Lao => Engine::Dictionary(State::root(include_bytes!("../tries/lo.bin"))),
             #[cfg(feature = "latin")]
-            Self::Latin => State::root(include_bytes!("../tries/la.bin")),
+            Self::Latin => Engine::Patterns(State::root(include_bytes!("../tries/la.bin"))),
+            #[cfg(feature = "latin-classic")]
+            Self::LatinClassic => Engine::Patterns(State::root(include_bytes!("../tries/la-x-classic.bin"))),
+            #[cfg(feature = "latin-liturgical")]
+            Self::LatinLiturgical => Engine::Patterns(State::root(include_bytes!("../tries/la-x-liturgic.bin"))),
+            #[cfg(feature = "latvian")]
+            Self::Latvian => Engine::Patterns(State::root(include_bytes!("../tries/lv.bin"))),
             #[cfg(feature = "lithuanian")]
-            Self::Lithuanian => State::root(include_bytes!("../tries/lt.bin")),
+            Self::Lithuanian => Engine::Patterns(State::root(include_bytes!("../tries/lt.bin"))),
             #[cfg(feature = "malayalam")]
-            Self::Malayalam => State::root(include_bytes!("../tries/ml.bin")),
+            Self::Malayalam => Engine::Patterns(State::root(include_bytes!("../tries/ml.bin"))),
             #[cfg(feature = "marathi")]
-            Self::Marathi => State::root(include_bytes!("../tries/mr.bin")),
+            Self::Marathi => Engine::Patterns(State::root(include_bytes!("../tries/mr.bin"))),
             #[cfg(feature = "mongolian")]
-            Self::Mongolian => State::root(include_bytes!("../tries/mn.bin")),
+            Self::Mongolian => Engine::Patterns(State::root(include_bytes!("../tries/mn.bin"))),
             #[cfg(feature = "norwegian")]
-            Self::Norwegian => State::root(include_bytes!("../tries/no.bin")),
+            Self::Norwegian => Engine::Patterns(State::root(include_bytes!("../tries/no.bin"))),
+            #[cfg(feature = "norwegian-bokmal")]
+            Self::NorwegianBokmal => Engine::Patterns(State::root(include_bytes!("../tries/no.bin"))),
+            #[cfg(feature = "norwegian-nynorsk")]
+            Self::NorwegianNynorsk => Engine::Patterns(State::root(include_bytes!("../tries/no.bin"))),
+            #[cfg(feature = "occitan")]
+            Self::Occitan => Engine::Patterns(State::root(include_bytes!("../tries/oc.bin"))),
             #[cfg(feature = "oriya")]
-            Self::Oriya => State::root(include_bytes!("../tries/or.bin")),
+            Self::Oriya => Engine::Patterns(State::root(include_bytes!("../tries/or.bin"))),
+            #[cfg(feature = "pali")]
+            Self::Pali => Engine::Patterns(State::root(include_bytes!("../tries/pi.bin"))),
             #[cfg(feature = "panjabi")]
-            Self::Panjabi => State::root(include_bytes!("../tries/pa.bin")),
+            Self::Panjabi => Engine::Patterns(State::root(include_bytes!("../tries/pa.bin"))),
+            #[cfg(feature = "piedmontese")]
+            Self::Piedmontese => Engine::Patterns(State::root(include_bytes!("../tries/pms.bin"))),
             #[cfg(feature = "polish")]
-            Self::Polish => State::root(include_bytes!("../tries/pl.bin")),
+            Self::Polish => Engine::Patterns(State::root(include_bytes!("../tries/pl.bin"))),
             #[cfg(feature = "portuguese")]
-            Self::Portuguese => State::root(include_bytes!("../tries/pt.bin")),
+            Self::Portuguese => Engine::Patterns(State::root(include_bytes!("../tries/pt.bin"))),
+            #[cfg(feature = "romanian")]
+            Self::Romanian => Engine::Patterns(State::root(include_bytes!("../tries/ro.bin"))),
+            #[cfg(feature = "romansh")]
+            Self::Romansh => Engine::Patterns(State::root(include_bytes!("../tries/rm.bin"))),
             #[cfg(feature = "russian")]
-            Self::Russian => State::root(include_bytes!("../tries/ru.bin")),
+            Self::Russian => Engine::Patterns(State::root(include_bytes!("../tries/ru.bin"))),
             #[cfg(feature = "sanskrit")]
-            Self::Sanskrit => State::root(include_bytes!("../tries/sa.bin")),
+            Self::Sanskrit => Engine::Patterns(State::root(include_bytes!("../tries/sa.bin"))),
             #[cfg(feature = "serbian")]
-            Self::Serbian => State::root(include_bytes!("../tries/sr.bin")),
+            Self::Serbian => Engine::Patterns(State::root(include_bytes!("../tries/sr.bin"))),
+            #[cfg(feature = "serbian-latin")]
+            Self::SerbianLatin => Engine::Patterns(State::root(include_bytes!("../tries/sr.bin"))),
             #[cfg(feature = "slovak")]
-            Self::Slovak => State::root(include_bytes!("../tries/sk.bin")),
+            Self::Slovak => Engine::Patterns(State::root(include_bytes!("../tries/sk.bin"))),
             #[cfg(feature = "slovenian")]
-            Self::Slovenian => State::root(include_bytes!("../tries/sl.bin")),
+            Self::Slovenian => Engine::Patterns(State::root(include_bytes!("../tries/sl.bin"))),
             #[cfg(feature = "spanish")]
-            Self::Spanish => State::root(include_bytes!("../tries/es.bin")),
+            Self::Spanish => Engine::Patterns(State::root(include_bytes!("../tries/es.bin"))),
             #[cfg(feature = "swedish")]
-            Self::Swedish => State::root(include_bytes!("../tries/sv.bin")),
+            Self::Swedish => Engine::Patterns(State::root(include_bytes!("../tries/sv.bin"))),
             #[cfg(feature = "tamil")]
-            Self::Tamil => State::root(include_bytes!("../tries/ta.bin")),
+            Self::Tamil => Engine::Patterns(State::root(include_bytes!("../tries/ta.bin"))),
             #[cfg(feature = "telugu")]
-            Self::Telugu => State::root(include_bytes!("../tries/te.bin")),
+            Self::Telugu => Engine::Patterns(State::root(include_bytes!("../tries/te.bin"))),
+            #[cfg(feature = "thai")]
+            Self::Thai => Engine::Dictionary(State::root(include_bytes!("../tries/th.bin"))),
             #[cfg(feature = "turkish")]
-            Self::Turkish => State::root(include_bytes!("../tries/tr.bin")),
+            Self::Turkish => Engine::Patterns(State::root(include_bytes!("../tries/tr.bin"))),
             #[cfg(feature = "turkmen")]
-            Self::Turkmen => State::root(include_bytes!("../tries/tk.bin")),
+            Self::Turkmen => Engine::Patterns(State::root(include_bytes!("../tries/tk.bin"))),
             #[cfg(feature = "ukrainian")]
-            Self::Ukrainian => State::root(include_bytes!("../tries/uk.bin")),
+            Self::Ukrainian => Engine::Patterns(State::root(include_bytes!("../tries/uk.bin"))),
+            #[cfg(feature = "upper-sorbian")]
+            Self::UpperSorbian => Engine::Patterns(State::root(include_bytes!("../tries/hsb.bin"))),
+            #[cfg(feature = "welsh")]
+            Self::Welsh => Engine::Patterns(State::root(include_bytes!("../tries/cy.bin"))),
         }
     }
 }
@@ -8,7 +8,12 @@ _hypher_ separates words into syllables.
   binary data with no up-front decoding.
 - No allocations unless when hyphenating very long words (> 45 bytes). You can
   disable the `alloc` feature, but then overly long words lead to a panic.
-- Support for many languages.
+- Support for many languages, including opt-in dictionary-based word
+  segmentation for Thai, Lao and Khmer, which are written without spaces
+  between words, and rule-based syllabification for syllabic scripts like
+  Hangul. For Thai, Lao and Khmer, only word boundaries are found, not
+  syllable boundaries within words.
+- A configurable vowel/consonant syllabifier for languages without patterns.
 - No unsafe code, no dependencies, no std.
 
 # Example
@@ -40,8 +45,8 @@ assert_eq!(syllables.next(), None);
 )]
 /*!
 # Languages
-By default, this crate supports hyphenating more than 30 languages.
-Embedding automata for all these languages will add ~1.1 MiB to your binary.
+By default, this crate supports hyphenating more than 70 languages.
+Embedding automata for all these languages will add ~1.4 MiB to your binary.
 Alternatively, you can disable support for all languages and manually choose
 which ones get added:
 
@@ -49,6 +54,11 @@ which ones get added:
 [dependencies]
 hypher = { version = "0.1", default-features = false, features = ["english", "greek"] }
 ```
+
+The word lists of Thai, Lao and Khmer take up ~1.5 MiB together, so these
+languages are not enabled by default. You can add them through their features
+`thai`, `lao` and `khmer`. Their tries are still part of the published crate,
+which makes its compressed download ~0.75 MiB larger.
 */
 
 #![no_std]
@@ -62,6 +72,33 @@ use core::fmt::{self, Debug, Formatter};
 use core::iter::FusedIterator;
 use core::num::NonZeroU8;
 
+#[cfg(feature = "alloc")]
+mod correct;
+#[cfg(feature = "alloc")]
+mod eval;
+mod grapheme;
+#[cfg(feature = "alloc")]
+mod parse;
+#[cfg(feature = "alloc")]
+mod patgen;
+#[cfg(feature = "alloc")]
+mod trie;
+
+#[cfg(feature = "alloc")]
+pub use self::correct::{correct, Correction};
+#[cfg(feature = "alloc")]
+pub use self::eval::{evaluate, Evaluation, Mismatch};
+#[cfg(feature = "alloc")]
+pub use self::parse::{parse_tex, Dic};
+#[cfg(feature = "alloc")]
+pub use self::patgen::{Level, Patgen, Stats};
+#[cfg(feature = "alloc")]
+pub use self::trie::{Patterns, Trie, TrieError};
+
+// The integration tests count grapheme clusters like the bounds do.
+#[doc(hidden)]
+pub use self::grapheme::ends as grapheme_ends;
+
 // Include language data.
 include!("lang.rs");
 
@@ -69,6 +106,16 @@ include!("lang.rs");
 ///
 /// Returns an iterator over the syllables.
 ///
+/// For languages written without spaces between words, like Thai, Lao and
+/// Khmer, this instead splits a run of text into the words of an embedded
+/// dictionary. Between these, lines break without a [hyphen](Lang::hyphen).
+/// The words themselves are not split into syllables, so a long word is
+/// never broken.
+///
+/// If the word contains soft hyphens, breaks are only reported right after
+/// those between two letters. Use [`hyphenate_stripped`] to hyphenate such
+/// words regardless.
+///
 /// This uses the default [bounds](Lang::bounds) for the language.
 ///
 /// # Panics
@@ -91,13 +138,17 @@ pub fn hyphenate(word: &str, lang: Lang) -> Syllables<'_> {
 }
 
 /// Segment a word into syllables, but forbid breaking between the given number
-/// of chars to each side.
+/// of grapheme clusters (user-perceived characters) to each side.
 ///
 /// Returns an iterator over the syllables.
 ///
+/// Like [`hyphenate`], this only breaks after soft hyphens if the word contains
+/// any.
+///
 /// # Panics
 /// Panics if the word is more than [`MAX_INLINE_SIZE`] bytes long and the `alloc`
-/// feature is disabled.
+/// feature is disabled. Serbian in Latin script is hyphenated through its
+/// Cyrillic transliteration, for which the same limit applies.
 ///
 /// # Example
 /// By setting the left bound to three, we forbid the possible break between
@@ -115,20 +166,433 @@ pub fn hyphenate_bounded(
     left_min: usize,
     right_min: usize,
 ) -> Syllables<'_> {
-    // Initialize the trie state for the language.
-    let root = lang.root();
+    let clusters = Clusters::new(word);
+    let levels = if word.contains(SOFT_HYPHEN) {
+        soft_hyphen_levels(word)
+    } else {
+        lang_levels(&clusters, lang, None, left_min, right_min)
+    };
+
+    // Break into segments at odd levels.
+    Syllables::new(&clusters, levels)
+}
+
+/// Segment a word into syllables as if it contained no soft hyphens, but forbid
+/// breaking between the given number of grapheme clusters to each side.
+///
+/// In contrast to [`hyphenate_bounded`], this ignores soft hyphens the author
+/// placed in the word. They are kept in the returned syllables, which are still
+/// slices of the original word.
+///
+/// # Panics
+/// Panics if the word without soft hyphens is more than [`MAX_INLINE_SIZE`]
+/// bytes long and the `alloc` feature is disabled.
+///
+/// # Example
+/// ```
+/// # use hypher::{hyphenate_stripped, Lang};
+/// let mut syllables = hyphenate_stripped("ex\u{ad}tensive", Lang::English, 2, 3);
+/// assert_eq!(syllables.next(), Some("ex\u{ad}"));
+/// assert_eq!(syllables.next(), Some("ten"));
+/// assert_eq!(syllables.next(), Some("sive"));
+/// assert_eq!(syllables.next(), None);
+/// ```
+pub fn hyphenate_stripped(
+    word: &str,
+    lang: Lang,
+    left_min: usize,
+    right_min: usize,
+) -> Syllables<'_> {
+    if !word.contains(SOFT_HYPHEN) {
+        return hyphenate_bounded(word, lang, left_min, right_min);
+    }
+
+    // Remove the soft hyphens.
+    let len = word.chars().filter(|&c| c != SOFT_HYPHEN).map(char::len_utf8).sum();
+    let mut clean = Bytes::zeros(len);
+    let clean_mut = clean.as_mut_slice();
+    let mut offset = 0;
+    for c in word.chars().filter(|&c| c != SOFT_HYPHEN) {
+        offset += c.encode_utf8(&mut clean_mut[offset..]).len();
+    }
+
+    let clean = core::str::from_utf8(clean.as_slice()).unwrap();
+    let inner = lang_levels(&Clusters::new(clean), lang, None, left_min, right_min);
+    let inner = inner.as_slice();
+
+    // Map the levels back to the byte indices in the original word. A break
+    // goes after the soft hyphens in front of a char.
+    let mut levels = Bytes::zeros(word.len().saturating_sub(1));
+    let levels_mut = levels.as_mut_slice();
+    let mut clean_start = 0;
+    for (i, c) in word.char_indices().filter(|&(_, c)| c != SOFT_HYPHEN) {
+        if clean_start > 0 {
+            levels_mut[i - 1] = inner[clean_start - 1];
+        }
+        clean_start += c.len_utf8();
+    }
+
+    Syllables::new(&Clusters::new(word), levels)
+}
+
+/// The soft hyphen, which marks where a word may be broken.
+const SOFT_HYPHEN: char = '\u{AD}';
+
+/// Compute the levels between each two inner bytes of a word with the engine
+/// of the language.
+///
+/// The patterns of the overlay, if any, are matched along with the language's
+/// own patterns.
+fn lang_levels(
+    clusters: &Clusters,
+    lang: Lang,
+    overlay: Option<State>,
+    left_min: usize,
+    right_min: usize,
+) -> Bytes {
+    match lang.engine() {
+        #[cfg(feature = "serbian-latin")]
+        Engine::Patterns(root) if lang == Lang::SerbianLatin => {
+            serbian_latin_levels(clusters.word, root, overlay, left_min, right_min)
+        }
+        Engine::Patterns(root) => {
+            compute_levels(clusters, root, overlay, left_min, right_min)
+        }
+        Engine::Dictionary(root) => segment_levels(clusters, root, left_min, right_min),
+        Engine::Syllabic => syllable_levels(clusters, left_min, right_min),
+    }
+}
+
+/// Compute levels that allow breaking exactly after the soft hyphens in the
+/// word, like TeX's `\-`.
+///
+/// These breaks were placed by the author and are thus not subject to the
+/// bounds. Only soft hyphens between two letters are honoured, so that no
+/// syllable consists of soft hyphens alone. Of several soft hyphens in a row,
+/// the break goes after the last one.
+fn soft_hyphen_levels(word: &str) -> Bytes {
+    let mut levels = Bytes::zeros(word.len().saturating_sub(1));
+    let levels_mut = levels.as_mut_slice();
+    for (i, c) in word.char_indices() {
+        let end = i + c.len_utf8();
+        let rest = &word[end..];
+        if c != SOFT_HYPHEN || rest.starts_with(SOFT_HYPHEN) {
+            continue;
+        }
+
+        let before = word[..i].chars().rev().find(|&c| c != SOFT_HYPHEN);
+        let after = rest.chars().next();
+        if before.map_or(false, char::is_alphabetic)
+            && after.map_or(false, char::is_alphabetic)
+        {
+            levels_mut[end - 1] = 1;
+        }
+    }
+    levels
+}
+
+/// Explain how the patterns of a language hyphenate a word.
+///
+/// Lists every pattern that matched the dotted word, together with the levels
+/// it contributed, and the winning level at each position. This is meant for
+/// debugging bad breaks and only available when the `alloc` feature is
+/// enabled.
+///
+/// Serbian in Latin script is explained through its Cyrillic transliteration.
+/// Languages that are not hyphenated through patterns have no matches.
+///
+/// # Example
+/// ```
+/// # use hypher::{hyphenate_explain, Lang};
+/// let explanation = hyphenate_explain("extensive", Lang::English);
+/// assert_eq!(explanation.dotted, ".extensive.");
+/// assert!(explanation.matches.iter().any(|m| m.pattern == "1siv"));
+/// assert_eq!(explanation.levels[3], 1); // e x | t e n
+/// ```
+#[cfg(feature = "alloc")]
+pub fn hyphenate_explain(word: &str, lang: Lang) -> Explanation {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    #[cfg(feature = "serbian-latin")]
+    let cyrillic;
+    #[cfg(feature = "serbian-latin")]
+    let word = if lang == Lang::SerbianLatin {
+        cyrillic = serbian_cyrillic(word);
+        core::str::from_utf8(cyrillic.as_slice()).unwrap()
+    } else {
+        word
+    };
+
+    let dotted = lowercase_and_dot(word);
+    let dotted = dotted.as_slice();
+    let mut matches = Vec::new();
+    let mut levels = alloc::vec![0; dotted.len() + 1];
+
+    if let Engine::Patterns(root) = lang.engine() {
+        for_each_match(dotted, root, |start, end, state| {
+            let mut pattern = Vec::new();
+            let mut contributed = Vec::new();
+            let mut prev = start;
+            for (offset, level) in state.levels() {
+                let split = start + offset;
+                pattern.extend_from_slice(&dotted[prev..split]);
+                pattern.push(b'0' + level);
+                contributed.push((split, level));
+                levels[split] = levels[split].max(level);
+                prev = split;
+            }
+            pattern.extend_from_slice(&dotted[prev..end]);
+
+            let pattern = String::from_utf8(pattern).unwrap();
+            matches.push(PatternMatch { pattern, start, levels: contributed });
+        });
+    }
+
+    let dotted = String::from_utf8(dotted.to_vec()).unwrap();
+    Explanation { dotted, matches, levels }
+}
+
+/// How the patterns of a language hyphenate a word.
+///
+/// This struct is created by [`hyphenate_explain`].
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Explanation {
+    /// The lowercased word with a dot before and after it, which is what the
+    /// patterns are matched against.
+    pub dotted: alloc::string::String,
+    /// The patterns that matched, ordered by start and then by length.
+    pub matches: alloc::vec::Vec<PatternMatch>,
+    /// The winning level before each byte of the dotted word and at its end,
+    /// i.e. the highest level any pattern placed there.
+    ///
+    /// Odd levels allow a break unless it is forbidden by the bounds, lies
+    /// within a grapheme cluster or splits an Indic akshara.
+    pub levels: alloc::vec::Vec<u8>,
+}
+
+/// A pattern that matched a word.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct PatternMatch {
+    /// The pattern with its levels, like `n2sive`.
+    pub pattern: alloc::string::String,
+    /// The byte offset in the dotted word at which the pattern matched.
+    pub start: usize,
+    /// The levels the pattern contributed, as pairs of the byte offset in the
+    /// dotted word before which they apply and the level.
+    pub levels: alloc::vec::Vec<(usize, u8)>,
+}
+
+/// A language with extra patterns laid over its own.
+///
+/// The extra patterns are compiled at runtime and matched along with the
+/// built-in ones. Where both place a level between two letters, the higher
+/// one wins. This allows patching a language without rebuilding its trie.
+/// Unlike an exception for a single word, a pattern like `1tion` or `.un1`
+/// applies to all words it matches.
+///
+/// Patterns for Serbian in Latin script are written in Cyrillic, as the word
+/// is hyphenated through its Cyrillic transliteration. Languages that are not
+/// hyphenated through patterns ignore the overlay.
+///
+/// This is only available when the `alloc` feature is enabled.
+///
+/// # Example
+/// ```
+/// # use hypher::{hyphenate, Lang, Overlay, Trie};
+/// assert_eq!(hyphenate("unicorn", Lang::English).join("-"), "uni-corn");
+///
+/// let overlay = Overlay::new(Lang::English, Trie::new([".un1", "i2c"]));
+/// assert_eq!(overlay.hyphenate("unicorn").join("-"), "un-icorn");
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Overlay {
+    lang: Lang,
+    trie: Trie,
+}
+
+#[cfg(feature = "alloc")]
+impl Overlay {
+    /// Lay the patterns in the trie over the language's own.
+    pub fn new(lang: Lang, trie: Trie) -> Self {
+        Self { lang, trie }
+    }
+
+    /// The language the patterns are laid over.
+    pub fn lang(&self) -> Lang {
+        self.lang
+    }
+
+    /// The extra patterns.
+    pub fn trie(&self) -> &Trie {
+        &self.trie
+    }
+
+    /// Segment a word into syllables like [`hyphenate`], but with the extra
+    /// patterns.
+    pub fn hyphenate<'a>(&self, word: &'a str) -> Syllables<'a> {
+        let (left_min, right_min) = self.lang.bounds();
+        self.hyphenate_bounded(word, left_min, right_min)
+    }
+
+    /// Segment a word into syllables like [`hyphenate_bounded`], but with the
+    /// extra patterns.
+    pub fn hyphenate_bounded<'a>(
+        &self,
+        word: &'a str,
+        left_min: usize,
+        right_min: usize,
+    ) -> Syllables<'a> {
+        let clusters = Clusters::new(word);
+        let levels = if word.contains(SOFT_HYPHEN) {
+            soft_hyphen_levels(word)
+        } else {
+            let overlay = Some(self.trie.root());
+            lang_levels(&clusters, self.lang, overlay, left_min, right_min)
+        };
+
+        Syllables::new(&clusters, levels)
+    }
+}
+
+impl Lang {
+    /// The patterns from which this language's trie was built, rebuilt by
+    /// walking the trie.
+    ///
+    /// Patterns are written like in a TeX pattern file, e.g. `.ab1c`, and
+    /// come in no particular order. For languages that are segmented through
+    /// a dictionary, this yields each word with a level 1 in front, which
+    /// marks where the word ends. Serbian in Latin script yields the Cyrillic
+    /// patterns it is hyphenated through and languages in syllabic scripts
+    /// yield nothing.
+    ///
+    /// This is only available when the `alloc` feature is enabled.
+    ///
+    /// # Example
+    /// ```
+    /// # use hypher::Lang;
+    /// let mut patterns = Lang::English.patterns();
+    /// assert!(patterns.any(|pattern| pattern == "1siv"));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn patterns(self) -> Patterns<'static> {
+        match self.engine() {
+            Engine::Patterns(root) | Engine::Dictionary(root) => Patterns::new(root),
+            Engine::Syllabic => Patterns::empty(),
+        }
+    }
+}
+
+/// Information about the hyphenation patterns of a language.
+///
+/// This struct is returned by [`Lang::metadata`].
+///
+/// # Example
+/// ```
+/// # use hypher::Lang;
+/// let metadata = Lang::English.metadata().unwrap();
+/// assert_eq!(metadata.title, "Hyphenation patterns for American English");
+/// assert_eq!(metadata.authors, ["Gerard D.C. Kuiken"]);
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Metadata {
+    /// The title of the pattern file.
+    pub title: &'static str,
+    /// The version of the patterns, often with or as a date.
+    pub version: Option<&'static str>,
+    /// The copyright notice.
+    pub copyright: Option<&'static str>,
+    /// The names of the authors.
+    pub authors: &'static [&'static str],
+    /// The licences under which the patterns are available. If there are
+    /// several, any of them may be chosen.
+    pub licences: &'static [Licence],
+}
+
+/// A licence under which hyphenation patterns are available.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Licence {
+    /// The name of the licence, like `MIT` or `LPPL`. Custom licences have
+    /// no name, but only a text.
+    pub name: Option<&'static str>,
+    /// The version of the licence.
+    pub version: Option<&'static str>,
+    /// Whether later versions of the licence may be chosen, too.
+    pub or_later: bool,
+    /// Where to find the licence.
+    pub url: Option<&'static str>,
+    /// The text of the licence.
+    pub text: Option<&'static str>,
+}
+
+/// How a language is segmented.
+#[allow(unused)]
+enum Engine {
+    /// Liang hyphenation patterns for breaking words into syllables.
+    Patterns(State<'static>),
+    /// A word list for breaking text without spaces into words.
+    Dictionary(State<'static>),
+    /// Rules for scripts in which each letter or block is a syllable.
+    Syllabic,
+}
+
+/// Compute the levels between each two inner bytes of the word.
+///
+/// Where the patterns of the overlay also match, the higher level wins.
+fn compute_levels(
+    clusters: &Clusters,
+    root: State,
+    overlay: Option<State>,
+    left_min: usize,
+    right_min: usize,
+) -> Bytes {
+    let word = clusters.word;
 
     // Lowercase and add dots before and after the word..
     let dotted = lowercase_and_dot(word);
     let dotted = dotted.as_slice();
 
     // Convert char bounds to byte bounds in the dotted word.
-    let (min_idx, max_idx) = char_to_byte_bounds(word, left_min, right_min);
+    let (min_idx, max_idx) = clusters.byte_bounds(left_min, right_min);
 
     // The levels between each two inner bytes of the word.
     let mut levels = Bytes::zeros(word.len().saturating_sub(1));
     let levels_mut = levels.as_mut_slice();
 
+    for root in core::iter::once(root).chain(overlay) {
+        for_each_match(dotted, root, |start, _, state| {
+            for (offset, level) in state.levels() {
+                let split = start + offset;
+
+                // Example
+                //
+                // Dotted: . h e l l o .
+                // Levels:    0 2 3 0
+                if split >= min_idx && split <= max_idx {
+                    let slot = &mut levels_mut[split - 2];
+                    *slot = (*slot).max(level);
+                }
+            }
+        });
+    }
+
+    // Never break within an Indic akshara, whatever the patterns say.
+    restrict_to_aksharas(word, levels_mut);
+
+    levels
+}
+
+/// Find the patterns that match the dotted word.
+///
+/// Calls `f` with the start and end of each match in the dotted word and the
+/// trie state at its end, which holds the pattern's levels.
+fn for_each_match<'a, F>(dotted: &[u8], root: State<'a>, mut f: F)
+where
+    F: FnMut(usize, usize, State<'a>),
+{
     // Start pattern matching at each character boundary.
     for start in 0..dotted.len() {
         if !is_char_boundary(dotted[start]) {
@@ -136,29 +600,470 @@ pub fn hyphenate_bounded(
         }
 
         let mut state = root;
-        for &b in &dotted[start..] {
+        for (i, &b) in dotted.iter().enumerate().skip(start) {
             if let Some(next) = state.transition(b) {
                 state = next;
-                for (offset, level) in state.levels() {
-                    let split = start + offset;
-
-                    // Example
-                    //
-                    // Dotted: . h e l l o .
-                    // Levels:    0 2 3 0
-                    if split >= min_idx && split <= max_idx {
-                        let slot = &mut levels_mut[split - 2];
-                        *slot = (*slot).max(level);
-                    }
+                if state.has_levels {
+                    f(start, i + 1, state);
                 }
             } else {
                 break;
             }
         }
     }
+}
 
-    // Break into segments at odd levels.
-    Syllables { word, cursor: 0, levels }
+/// Clear the levels that would split an akshara, the orthographic syllable of
+/// Indic scripts.
+///
+/// An akshara holds the dependent vowel signs and other marks of its letters,
+/// all consonants joined into a conjunct through a virama and any zero width
+/// (non-)joiner. Tamil is an exception: its virama, the pulli, closes a
+/// syllable instead of forming a conjunct.
+fn restrict_to_aksharas(word: &str, levels: &mut [u8]) {
+    let pairs = word.char_indices().zip(word.char_indices().skip(1));
+    for ((_, prev), (i, next)) in pairs {
+        let joined = matches!(prev, '\u{200C}' | '\u{200D}')
+            || matches!(next, '\u{200C}' | '\u{200D}')
+            || is_virama(prev)
+            || is_indic_mark(next);
+        if joined && levels[i - 1] % 2 == 1 {
+            levels[i - 1] = 0;
+        }
+    }
+}
+
+/// Whether a char joins an Indic consonant with the one after it.
+fn is_virama(c: char) -> bool {
+    match c {
+        '\u{BCD}' => false,
+        '\u{D3B}' | '\u{D3C}' | '\u{D4E}' => true,
+        '\u{900}'..='\u{D7F}' => (c as u32 - 0x900) % 0x80 == 0x4D,
+        _ => false,
+    }
+}
+
+/// Whether a char is a dependent vowel sign, nukta, virama or other mark that
+/// attaches to the Indic letter before it.
+fn is_indic_mark(c: char) -> bool {
+    match c {
+        '\u{9CE}' | '\u{B83}' | '\u{D4E}' | '\u{D54}'..='\u{D56}' => false,
+        '\u{9FE}' | '\u{A70}' | '\u{A71}' | '\u{A75}' | '\u{AFA}'..='\u{AFF}' => true,
+        '\u{C04}' | '\u{A8E0}'..='\u{A8F1}' => true,
+        '\u{900}'..='\u{D7F}' => matches!(
+            (c as u32 - 0x900) % 0x80,
+            0x00..=0x03 | 0x3A..=0x3C | 0x3E..=0x4F | 0x51..=0x57 | 0x62..=0x63
+        ),
+        _ => false,
+    }
+}
+
+/// Compute the levels between each two inner bytes of a run of text without
+/// spaces, with odd levels at the boundaries of the words found in the
+/// dictionary.
+fn segment_levels(
+    clusters: &Clusters,
+    root: State,
+    left_min: usize,
+    right_min: usize,
+) -> Bytes {
+    let text = clusters.word;
+
+    // Convert char bounds to byte bounds in the text. The indices are
+    // computed for the dotted text, which is one byte ahead.
+    let (min_idx, max_idx) = clusters.byte_bounds(left_min, right_min);
+    let allowed = min_idx - 1..=max_idx - 1;
+
+    let mut levels = Bytes::zeros(text.len().saturating_sub(1));
+    let levels_mut = levels.as_mut_slice();
+
+    let mut start = 0;
+    while start < text.len() {
+        let end = next_word(text, start, root);
+        if end < text.len() && allowed.contains(&end) {
+            levels_mut[end - 1] = 1;
+        }
+        start = end;
+    }
+
+    levels
+}
+
+/// Find the end of the word starting at `start`.
+///
+/// Of the dictionary words starting here, this picks the longest one after
+/// which the text continues with the most dictionary words (looking two words
+/// ahead). Text that is not in the dictionary is skipped cluster by cluster
+/// until the next dictionary word starts.
+fn next_word(text: &str, start: usize, root: State) -> usize {
+    let mut best: Option<(usize, usize)> = None;
+    for end in word_ends(text, start, root) {
+        let score = lookahead(text, end, root, 2);
+        if best.map_or(true, |(_, best)| score >= best) {
+            best = Some((end, score));
+        }
+    }
+
+    if let Some((end, _)) = best {
+        return end;
+    }
+
+    let mut end = start;
+    loop {
+        end = next_cluster(text, end);
+        if end >= text.len() || word_ends(text, end, root).next().is_some() {
+            return end;
+        }
+    }
+}
+
+/// How many dictionary words, up to `depth`, can follow each other starting
+/// at `pos`. Reaching the end of the text counts as reaching the full depth.
+fn lookahead(text: &str, pos: usize, root: State, depth: usize) -> usize {
+    if pos == text.len() {
+        return depth;
+    } else if depth == 0 {
+        return 0;
+    }
+
+    word_ends(text, pos, root)
+        .map(|end| 1 + lookahead(text, end, root, depth - 1))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Iterate over the ends of the dictionary words starting at `start`, from
+/// short to long.
+///
+/// Words that would be followed by a dependent mark are skipped since the
+/// mark belongs to the last letter of the word.
+fn word_ends<'a>(
+    text: &'a str,
+    start: usize,
+    root: State<'a>,
+) -> impl Iterator<Item = usize> + 'a {
+    let mut state = Some(root);
+    let mut pos = start;
+    core::iter::from_fn(move || {
+        while let Some(prev) = state {
+            let next = text.as_bytes().get(pos).and_then(|&b| prev.transition(b));
+            state = next;
+            pos += 1;
+            if next.map_or(false, |next| !next.levels.is_empty())
+                && text[pos..].chars().next().map_or(true, |c| !is_dependent(c))
+            {
+                return Some(pos);
+            }
+        }
+        None
+    })
+}
+
+/// Find the end of the cluster starting at `pos`.
+fn next_cluster(text: &str, pos: usize) -> usize {
+    let mut chars = text[pos..].chars().peekable();
+    let mut end = pos;
+    while let Some(c) = chars.next() {
+        end += c.len_utf8();
+        if !is_joining(c) && !chars.peek().copied().map_or(false, is_dependent) {
+            break;
+        }
+    }
+    end
+}
+
+/// Whether a char attaches to the char before it, like a combining mark or a
+/// vowel sign or tone mark in Thai, Lao or Khmer.
+fn is_dependent(c: char) -> bool {
+    matches!(
+        c,
+        '\u{300}'..='\u{36F}'
+            | '\u{135D}'..='\u{135F}'
+            | '\u{1AB0}'..='\u{1AFF}'
+            | '\u{1DC0}'..='\u{1DFF}'
+            | '\u{20D0}'..='\u{20FF}'
+            | '\u{FE00}'..='\u{FE0F}'
+            | '\u{FE20}'..='\u{FE2F}'
+            | '\u{E30}'..='\u{E3A}'
+            | '\u{E45}'..='\u{E4E}'
+            | '\u{EB0}'..='\u{EBC}'
+            | '\u{EC6}'..='\u{ECE}'
+            | '\u{17B4}'..='\u{17D3}'
+            | '\u{17DD}'
+            | '\u{200C}'..='\u{200D}'
+    )
+}
+
+/// Whether a char attaches to the char after it, like a Thai or Lao vowel
+/// written before its consonant or the Khmer sign that stacks consonants.
+fn is_joining(c: char) -> bool {
+    matches!(c, '\u{E40}'..='\u{E44}' | '\u{EC0}'..='\u{EC4}' | '\u{17D2}')
+}
+
+/// Compute the levels between each two inner bytes of a word in a syllabic
+/// script, with odd levels between each two syllables.
+///
+/// Breaks are only placed between two syllables of a syllabic script, not next
+/// to digits, punctuation or letters of other scripts.
+fn syllable_levels(clusters: &Clusters, left_min: usize, right_min: usize) -> Bytes {
+    let word = clusters.word;
+    let (min_idx, max_idx) = clusters.byte_bounds(left_min, right_min);
+    let allowed = min_idx - 1..=max_idx - 1;
+
+    let mut levels = Bytes::zeros(word.len().saturating_sub(1));
+    let levels_mut = levels.as_mut_slice();
+
+    let mut start = 0;
+    let mut prev = false;
+    while start < word.len() {
+        let (end, syllabic) = next_syllable(word, start);
+        if prev && syllabic && allowed.contains(&start) {
+            levels_mut[start - 1] = 1;
+        }
+        prev = syllabic;
+        start = end;
+    }
+
+    levels
+}
+
+/// Find the end of the syllable starting at `start` and whether it belongs to
+/// a syllabic script.
+///
+/// A syllable extends over combining marks, the jamo of a Hangul syllable
+/// block and the final consonants of Canadian Syllabics. The Cherokee letter
+/// `Ꮝ`, which stands for a lone `s`, is joined with the syllable after it.
+fn next_syllable(word: &str, start: usize) -> (usize, bool) {
+    let mut chars = word[start..].chars();
+    let first = match chars.next() {
+        Some(c) => c,
+        None => return (start, false),
+    };
+    let mut end = start + first.len_utf8();
+    let mut prev = first;
+    for c in chars {
+        let joined = is_dependent(c)
+            || is_syllabics_final(c)
+            || matches!(prev, '\u{13CD}' | '\u{AB9D}')
+            || joins_hangul(prev, c);
+        if !joined {
+            break;
+        }
+        end += c.len_utf8();
+        prev = c;
+    }
+    (end, is_syllabic(first))
+}
+
+/// Whether a char is a syllable of Hangul, Ethiopic, Cherokee or Canadian
+/// Syllabics.
+fn is_syllabic(c: char) -> bool {
+    hangul(c).is_some()
+        || matches!(
+            c,
+            '\u{1200}'..='\u{135A}'
+                | '\u{1380}'..='\u{138F}'
+                | '\u{2D80}'..='\u{2DDE}'
+                | '\u{AB01}'..='\u{AB2E}'
+                | '\u{1E7E0}'..='\u{1E7FE}'
+                | '\u{13A0}'..='\u{13F5}'
+                | '\u{13F8}'..='\u{13FD}'
+                | '\u{AB70}'..='\u{ABBF}'
+                | '\u{1401}'..='\u{166C}'
+                | '\u{166F}'..='\u{167F}'
+                | '\u{18B0}'..='\u{18F5}'
+                | '\u{11AB0}'..='\u{11ABF}'
+        )
+}
+
+/// Whether a char is a final consonant in Canadian Syllabics, which closes
+/// the syllable before it.
+fn is_syllabics_final(c: char) -> bool {
+    matches!(
+        c,
+        '\u{141F}'..='\u{142A}'
+            | '\u{1449}'
+            | '\u{1466}'
+            | '\u{1483}'..='\u{1484}'
+            | '\u{14A1}'
+            | '\u{14BB}'
+            | '\u{14BD}'
+            | '\u{14D0}'
+            | '\u{14D2}'
+            | '\u{14EA}'
+            | '\u{14EC}'
+            | '\u{1505}'
+            | '\u{1507}'
+            | '\u{1525}'
+            | '\u{153E}'
+            | '\u{1550}'
+            | '\u{1552}'
+            | '\u{155D}'
+            | '\u{156A}'
+            | '\u{156F}'
+            | '\u{157D}'
+            | '\u{1585}'
+            | '\u{1595}'..='\u{1596}'
+            | '\u{15A6}'
+            | '\u{167E}'
+            | '\u{18DE}'..='\u{18DF}'
+    )
+}
+
+/// Whether two chars belong to the same Hangul syllable block.
+fn joins_hangul(prev: char, c: char) -> bool {
+    use Jamo::*;
+    matches!(
+        (hangul(prev), hangul(c)),
+        (Some(Leading), Some(Leading | Vowel | Open | Closed))
+            | (Some(Vowel | Open), Some(Vowel | Trailing))
+            | (Some(Trailing | Closed), Some(Trailing))
+    )
+}
+
+/// The role of a char in a Hangul syllable block.
+#[derive(Copy, Clone)]
+enum Jamo {
+    /// A leading consonant.
+    Leading,
+    /// A vowel.
+    Vowel,
+    /// A trailing consonant.
+    Trailing,
+    /// A precomposed syllable without trailing consonant.
+    Open,
+    /// A precomposed syllable with trailing consonant.
+    Closed,
+}
+
+/// Determine the role of a char in a Hangul syllable block.
+fn hangul(c: char) -> Option<Jamo> {
+    Some(match c {
+        '\u{1100}'..='\u{115F}' | '\u{A960}'..='\u{A97C}' => Jamo::Leading,
+        '\u{1160}'..='\u{11A7}' | '\u{D7B0}'..='\u{D7C6}' => Jamo::Vowel,
+        '\u{11A8}'..='\u{11FF}' | '\u{D7CB}'..='\u{D7FB}' => Jamo::Trailing,
+        '\u{AC00}'..='\u{D7A3}' if (c as u32 - 0xAC00) % 28 == 0 => Jamo::Open,
+        '\u{AC00}'..='\u{D7A3}' => Jamo::Closed,
+        _ => return None,
+    })
+}
+
+/// Compute the levels for a Serbian word in Latin script by hyphenating its
+/// Cyrillic transliteration.
+///
+/// The Latin digraphs `lj`, `nj` and `dž` are single letters in Cyrillic. Going
+/// through the Cyrillic patterns thus ensures that both scripts break the same
+/// word in the same places and that the bounds count letters, not chars.
+#[cfg(feature = "serbian-latin")]
+fn serbian_latin_levels(
+    word: &str,
+    root: State,
+    overlay: Option<State>,
+    left_min: usize,
+    right_min: usize,
+) -> Bytes {
+    let cyrillic = serbian_cyrillic(word);
+    let cyrillic = core::str::from_utf8(cyrillic.as_slice()).unwrap();
+    let inner =
+        compute_levels(&Clusters::new(cyrillic), root, overlay, left_min, right_min);
+    let inner = inner.as_slice();
+
+    // Map the levels back to the byte indices in the Latin word.
+    let mut levels = Bytes::zeros(word.len().saturating_sub(1));
+    let levels_mut = levels.as_mut_slice();
+    let (mut latin_end, mut cyrillic_end) = (0, 0);
+    for (c, latin_len) in serbian_letters(word) {
+        latin_end += latin_len;
+        cyrillic_end += c.len_utf8();
+        if latin_end < word.len() {
+            levels_mut[latin_end - 1] = inner[cyrillic_end - 1];
+        }
+    }
+
+    levels
+}
+
+/// Transliterate a Serbian word from Latin to lowercase Cyrillic script.
+#[cfg(feature = "serbian-latin")]
+fn serbian_cyrillic(word: &str) -> Bytes {
+    let len = serbian_letters(word).map(|(c, _)| c.len_utf8()).sum();
+    let mut cyrillic = Bytes::zeros(len);
+    let cyrillic_mut = cyrillic.as_mut_slice();
+    let mut offset = 0;
+    for (c, _) in serbian_letters(word) {
+        offset += c.encode_utf8(&mut cyrillic_mut[offset..]).len();
+    }
+    cyrillic
+}
+
+/// Iterate over the letters of a Serbian word in Latin script.
+///
+/// Yields for each letter its lowercase Cyrillic equivalent and its length in
+/// bytes. Chars that are not part of the Serbian Latin alphabet are passed
+/// through unchanged.
+#[cfg(feature = "serbian-latin")]
+fn serbian_letters(word: &str) -> impl Iterator<Item = (char, usize)> + '_ {
+    let mut chars = word.chars().peekable();
+    core::iter::from_fn(move || {
+        let first = chars.next()?;
+        let second = chars.peek().copied();
+        let digraph = match (lowercase(first), second.map(lowercase)) {
+            ('l', Some('j')) => Some('љ'),
+            ('n', Some('j')) => Some('њ'),
+            ('d', Some('ž')) => Some('џ'),
+            _ => None,
+        };
+
+        if let (Some(c), Some(second)) = (digraph, second) {
+            chars.next();
+            return Some((c, first.len_utf8() + second.len_utf8()));
+        }
+
+        let c = match lowercase(first) {
+            'a' => 'а',
+            'b' => 'б',
+            'c' => 'ц',
+            'č' => 'ч',
+            'ć' => 'ћ',
+            'd' => 'д',
+            'đ' => 'ђ',
+            'e' => 'е',
+            'f' => 'ф',
+            'g' => 'г',
+            'h' => 'х',
+            'i' => 'и',
+            'j' => 'ј',
+            'k' => 'к',
+            'l' => 'л',
+            'm' => 'м',
+            'n' => 'н',
+            'o' => 'о',
+            'p' => 'п',
+            'r' => 'р',
+            's' => 'с',
+            'š' => 'ш',
+            't' => 'т',
+            'u' => 'у',
+            'v' => 'в',
+            'z' => 'з',
+            'ž' => 'ж',
+            'ǉ' => 'љ',
+            'ǌ' => 'њ',
+            'ǆ' => 'џ',
+            _ => first,
+        };
+
+        Some((c, first.len_utf8()))
+    })
+}
+
+/// Lowercase a char if it has a single-char lowercase mapping.
+fn lowercase(c: char) -> char {
+    let mut lower = c.to_lowercase();
+    match (lower.next(), lower.next()) {
+        (Some(l), None) => l,
+        _ => c,
+    }
 }
 
 /// Lowercase a word and add dots before and after it.
@@ -187,23 +1092,195 @@ fn lowercase_and_dot(word: &str) -> Bytes {
     dotted
 }
 
-/// Convert char bounds to byte bounds in the dotted word.
-fn char_to_byte_bounds(word: &str, left_min: usize, right_min: usize) -> (usize, usize) {
-    // It makes no sense to split outside the word.
-    let left_min = left_min.max(1);
-    let right_min = right_min.max(1);
+/// The grapheme cluster boundaries of a word.
+///
+/// They are found once per word and shared by the conversion of the bounds and
+/// the filtering of the breaks.
+struct Clusters<'a> {
+    /// The word.
+    word: &'a str,
+    /// A nonzero byte for each inner byte offset at which a cluster starts,
+    /// shifted by one like the levels. `None` if each char is its own cluster.
+    starts: Option<Bytes>,
+}
+
+impl<'a> Clusters<'a> {
+    /// Find the cluster boundaries of a word.
+    fn new(word: &'a str) -> Self {
+        // No two chars below U+0300 join into a cluster, except for CR LF. As
+        // all of them are encoded with bytes below 0xCC, the common case of
+        // plain text doesn't need the full segmentation.
+        if word.bytes().all(|b| b < 0xCC) && !word.contains("\r\n") {
+            return Self { word, starts: None };
+        }
+
+        let mut starts = Bytes::zeros(word.len().saturating_sub(1));
+        let starts_mut = starts.as_mut_slice();
+        for end in grapheme::ends(word).filter(|&end| end < word.len()) {
+            starts_mut[end - 1] = 1;
+        }
+
+        Self { word, starts: Some(starts) }
+    }
+
+    /// Whether a cluster starts at an inner byte offset of the word.
+    fn is_start(&self, i: usize) -> bool {
+        match &self.starts {
+            Some(starts) => i > 0 && starts.as_slice().get(i - 1) == Some(&1),
+            None => i > 0 && i < self.word.len() && self.word.is_char_boundary(i),
+        }
+    }
+
+    /// Iterate over the inner byte offsets at which a cluster starts.
+    fn starts(&self) -> impl DoubleEndedIterator<Item = usize> + '_ {
+        self.word.char_indices().map(|(i, _)| i).filter(|&i| self.is_start(i))
+    }
+
+    /// Convert bounds in grapheme clusters to byte bounds in the dotted word.
+    fn byte_bounds(&self, left_min: usize, right_min: usize) -> (usize, usize) {
+        // It makes no sense to split outside the word.
+        let left_min = left_min.max(1);
+        let right_min = right_min.max(1);
+
+        // Convert from clusters to byte indices in the dotted word.
+        let min_idx = 1 + self.starts().nth(left_min - 1).unwrap_or(self.word.len());
+        let max_idx = 1 + self.starts().rev().nth(right_min - 1).unwrap_or(0);
+
+        (min_idx, max_idx)
+    }
+
+    /// Drop the breaks within a cluster from the levels between each two inner
+    /// bytes of the word.
+    fn filter(&self, levels: &mut [u8]) {
+        if let Some(starts) = &self.starts {
+            for (level, &start) in levels.iter_mut().zip(starts.as_slice()) {
+                if start == 0 && *level % 2 == 1 {
+                    *level = 0;
+                }
+            }
+        }
+    }
+}
+
+/// Rules for segmenting words into syllables in a language without
+/// hyphenation patterns.
+///
+/// Each vowel forms the nucleus of a syllable. Of the consonants between two
+/// vowels, the next syllable receives as many as form an allowed onset and the
+/// previous syllable receives the rest. A single consonant is always an allowed
+/// onset, longer ones must be listed. Vowels and onsets are matched case
+/// insensitively.
+///
+/// # Example
+/// ```
+/// # use hypher::Syllabifier;
+/// let swahili = Syllabifier::new("aeiou").onsets(&["mb", "nd", "ng", "ny", "sh"]);
+/// let mut syllables = swahili.syllabify("ninakupenda");
+/// assert_eq!(syllables.next(), Some("ni"));
+/// assert_eq!(syllables.next(), Some("na"));
+/// assert_eq!(syllables.next(), Some("ku"));
+/// assert_eq!(syllables.next(), Some("pe"));
+/// assert_eq!(syllables.next(), Some("nda"));
+/// assert_eq!(syllables.next(), None);
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Syllabifier<'a> {
+    vowels: &'a str,
+    onsets: &'a [&'a str],
+    left_min: usize,
+    right_min: usize,
+}
+
+impl<'a> Syllabifier<'a> {
+    /// Create a syllabifier for a language with the given vowels.
+    ///
+    /// Forbids breaking between two grapheme clusters to each side of the word
+    /// by default.
+    pub const fn new(vowels: &'a str) -> Self {
+        Self { vowels, onsets: &[], left_min: 2, right_min: 2 }
+    }
+
+    /// Set the consonant clusters that may start a syllable.
+    pub const fn onsets(mut self, onsets: &'a [&'a str]) -> Self {
+        self.onsets = onsets;
+        self
+    }
+
+    /// Set the number of grapheme clusters to each side between which breaking
+    /// is forbidden.
+    pub const fn bounds(mut self, left_min: usize, right_min: usize) -> Self {
+        self.left_min = left_min;
+        self.right_min = right_min;
+        self
+    }
+
+    /// Segment a word into syllables.
+    ///
+    /// Returns an iterator over the syllables.
+    ///
+    /// # Panics
+    /// Panics if the word is more than [`MAX_INLINE_SIZE`] bytes long and the
+    /// `alloc` feature is disabled.
+    pub fn syllabify<'w>(&self, word: &'w str) -> Syllables<'w> {
+        let clusters = Clusters::new(word);
+        let (min_idx, max_idx) = clusters.byte_bounds(self.left_min, self.right_min);
+        let allowed = min_idx - 1..=max_idx - 1;
+
+        let mut levels = Bytes::zeros(word.len().saturating_sub(1));
+        let levels_mut = levels.as_mut_slice();
+
+        // Split the consonants between each two vowels.
+        let mut prev = None;
+        for (i, c) in word.char_indices() {
+            if !self.is_vowel(c) {
+                continue;
+            }
+
+            if let Some(end) = prev {
+                let split = self.onset_start(word, end, i);
+                if allowed.contains(&split) {
+                    levels_mut[split - 1] = 1;
+                }
+            }
+
+            prev = Some(i + c.len_utf8());
+        }
+
+        Syllables::new(&clusters, levels)
+    }
 
-    // Convert from chars to byte indices in the dotted word.
-    let min_idx = 1 + word.chars().take(left_min).map(char::len_utf8).sum::<usize>();
-    let max_idx = 1 + word.len()
-        - word.chars().rev().take(right_min).map(char::len_utf8).sum::<usize>();
+    /// Whether a char is one of the vowels.
+    fn is_vowel(&self, c: char) -> bool {
+        let c = lowercase(c);
+        self.vowels.chars().any(|v| lowercase(v) == c)
+    }
 
-    (min_idx, max_idx)
+    /// Find where the syllable starts that has its vowel at `vowel`, given
+    /// that the previous vowel ends at `end`.
+    fn onset_start(&self, word: &str, end: usize, vowel: usize) -> usize {
+        word[end..vowel]
+            .char_indices()
+            .map(|(i, _)| end + i)
+            .filter(|&i| !word[i..].starts_with(is_dependent))
+            .find(|&i| self.is_onset(&word[i..vowel]))
+            .unwrap_or(vowel)
+    }
+
+    /// Whether a consonant cluster may start a syllable.
+    fn is_onset(&self, cluster: &str) -> bool {
+        let mut chars = cluster.chars();
+        let single = chars.next().is_some() && chars.all(is_dependent);
+        single
+            || self.onsets.iter().any(|onset| {
+                onset.chars().map(lowercase).eq(cluster.chars().map(lowercase))
+            })
+    }
 }
 
 /// An iterator over the syllables of a word.
 ///
-/// This struct is created by [`hyphenate`] and [`hyphenate_bounded`].
+/// This struct is created by [`hyphenate`], [`hyphenate_bounded`] and
+/// [`Syllabifier::syllabify`].
 #[derive(Debug, Clone)]
 pub struct Syllables<'a> {
     word: &'a str,
@@ -211,7 +1288,17 @@ pub struct Syllables<'a> {
     levels: Bytes,
 }
 
-impl Syllables<'_> {
+impl<'a> Syllables<'a> {
+    /// Create an iterator over the syllables given by the levels between each
+    /// two inner bytes of the word.
+    ///
+    /// Whatever produced the levels, breaks within a grapheme cluster, e.g.
+    /// before a combining mark or inside an emoji sequence, are dropped here.
+    fn new(clusters: &Clusters<'a>, mut levels: Bytes) -> Self {
+        clusters.filter(levels.as_mut_slice());
+        Self { word: clusters.word, cursor: 0, levels }
+    }
+
     /// Join the syllables with a separator like a hyphen or soft hyphen.
     ///
     /// This is only available when the `alloc` feature is enabled.
@@ -254,7 +1341,7 @@ impl<'a> Iterator for Syllables<'a> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let len = if self.word.is_empty() { 0 } else { 1 + self.splits() };
+        let len = if self.cursor == self.word.len() { 0 } else { 1 + self.splits() };
         (len, Some(len))
     }
 }
@@ -350,11 +1437,18 @@ impl Debug for Bytes {
 }
 
 /// A state in a trie traversal.
+///
+/// The trie data must be valid, as decoding indexes into it unchecked and
+/// thus panics on malformed data. The embedded tries are checked when they
+/// are generated, and bytes from elsewhere only become a `Trie` through
+/// `Trie::from_bytes`, which accepts exactly the bytes that recompiling
+/// their patterns gives back.
 #[derive(Copy, Clone)]
 struct State<'a> {
     data: &'a [u8],
     addr: usize,
     stride: usize,
+    has_levels: bool,
     levels: &'a [u8],
     trans: &'a [u8],
     targets: &'a [u8],
@@ -403,18 +1497,30 @@ impl<'a> State<'a> {
 
         // Decode the targets.
         let targets = &node[pos..pos + stride * count];
-        Self { data, addr, stride, levels, trans, targets }
+        Self {
+            data,
+            addr,
+            stride,
+            has_levels,
+            levels,
+            trans,
+            targets,
+        }
     }
 
     /// Return the state reached by following the transition labelled `b`.
     /// Returns `None` if there is no such state.
     fn transition(self, b: u8) -> Option<Self> {
-        self.trans.iter().position(|&x| x == b).map(|idx| {
-            let offset = self.stride * idx;
-            let delta = from_be_bytes(&self.targets[offset..offset + self.stride]);
-            let next = (self.addr as isize + delta) as usize;
-            Self::at(self.data, next)
-        })
+        self.trans.iter().position(|&x| x == b).map(|idx| self.target(idx))
+    }
+
+    /// Return the state reached by following the transition with the given
+    /// index.
+    fn target(self, idx: usize) -> Self {
+        let offset = self.stride * idx;
+        let delta = from_be_bytes(&self.targets[offset..offset + self.stride]);
+        let next = (self.addr as isize + delta) as usize;
+        Self::at(self.data, next)
     }
 
     /// Returns the levels contained in the state.
@@ -453,7 +1559,10 @@ fn is_char_boundary(b: u8) -> bool {
 
 #[cfg(test)]
 mod tests {
-    use super::{hyphenate, Lang, MAX_INLINE_SIZE};
+    use super::{
+        hyphenate, hyphenate_bounded, hyphenate_stripped, Clusters, Lang, Syllabifier,
+        MAX_INLINE_SIZE,
+    };
 
     #[allow(unused)]
     use Lang::*;
@@ -478,6 +1587,10 @@ mod tests {
         assert_eq!(hyphenate("", English).len(), 0);
         assert_eq!(hyphenate("hello", English).len(), 1);
         assert_eq!(hyphenate("extensive", English).len(), 3);
+
+        let mut syllables = hyphenate("hello", English);
+        syllables.next();
+        assert_eq!(syllables.len(), 0);
     }
 
     const LONG_WORD: &str = "thisisaverylongstringwithanunrealisticwordlengthforenglishbutitmightbepossibleinanotherlanguage";
@@ -551,6 +1664,34 @@ mod tests {
         test(Georgian, "კარ-ტო-ფი-ლი");
     }
 
+    #[test]
+    #[cfg(all(feature = "norwegian-bokmal", feature = "norwegian-nynorsk"))]
+    fn test_norwegian() {
+        test(NorwegianBokmal, "data-ma-skin");
+        test(NorwegianBokmal, "uni-ver-si-tet");
+        test(NorwegianNynorsk, "for-kla-ring");
+        test(NorwegianNynorsk, "kjær-leik");
+        assert_eq!(Lang::from_iso(*b"nb"), Some(NorwegianBokmal));
+        assert_eq!(Lang::from_iso(*b"nn"), Some(NorwegianNynorsk));
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "latin",
+        feature = "latin-classic",
+        feature = "latin-liturgical"
+    ))]
+    fn test_latin() {
+        test(Latin, "ma-gnus");
+        test(Latin, "Al-lel-uia");
+        test(LatinClassic, "mag-nus");
+        test(LatinClassic, "vi-rum-que");
+        test(LatinClassic, "quō-rum");
+        test(LatinLiturgical, "be-ne-dic-tus");
+        test(LatinLiturgical, "ele-i-son");
+        test(LatinLiturgical, "Al-le-lu-ia");
+    }
+
     #[test]
     #[cfg(feature = "polish")]
     fn test_polish() {
@@ -565,17 +1706,529 @@ mod tests {
         test(Czech, "br-něn-ský");
     }
 
+    #[test]
+    #[cfg(all(feature = "serbian", feature = "serbian-latin"))]
+    fn test_serbian() {
+        test(Serbian, "ра-чу-нар");
+        test(Serbian, "при-ја-тељ");
+        test(Serbian, "по-љо-при-вре-да");
+        test(SerbianLatin, "ra-ču-nar");
+        test(SerbianLatin, "pri-ja-telj");
+        test(SerbianLatin, "Po-ljo-pri-vre-da");
+        test(SerbianLatin, "DŽA-MI-JA");
+        assert_eq!(Lang::from_iso_and_script(*b"sr", *b"Cyrl"), Some(Serbian));
+        assert_eq!(Lang::from_iso_and_script(*b"sr", *b"Latn"), Some(SerbianLatin));
+        assert_eq!(Lang::from_iso(*b"sr"), Some(Serbian));
+    }
+
+    #[test]
+    #[cfg(all(feature = "serbian", feature = "sanskrit", feature = "catalan"))]
+    fn test_metadata() {
+        // Sanskrit's bounds come from the hyphenmins of its pattern file.
+        assert_eq!(Sanskrit.bounds(), (1, 3));
+        assert_eq!(Serbian.bounds(), (2, 2));
+        assert_eq!(Catalan.bounds(), (2, 2));
+        assert_eq!(Catalan.metadata(), None);
+
+        let metadata = Serbian.metadata().unwrap();
+        assert_eq!(metadata.version, Some("2.02 Jun 22, 2008"));
+        assert_eq!(metadata.authors, ["Dejan Muhamedagić"]);
+        assert_eq!(metadata.licences[0].name, Some("LPPL"));
+        assert!(metadata.licences[0].or_later);
+    }
+
     #[test]
     #[cfg(feature = "malayalam")]
     fn test_malayalam() {
         test(Malayalam, "വി-ദ്യാർ-ത്ഥി");
-        test(Malayalam, "പഠി-ക്കുക");
+        test(Malayalam, "പ-ഠി-ക്കു-ക");
     }
 
     #[test]
     #[cfg(feature = "hindi")]
     fn test_hindi() {
         test(Hindi, "वि-द्या-र्थी");
-        test(Hindi, "पढ़-ना");
+        test(Hindi, "प-ढ़-ना");
+        test(Hindi, "क्\u{200C}ष-मा");
+        test(Hindi, "क्\u{200D}ष-मा");
+    }
+
+    #[test]
+    #[cfg(feature = "bengali")]
+    fn test_bengali() {
+        test(Bengali, "প-ড়া-শো-না");
+        test(Bengali, "ক্\u{200C}ষ-ণ");
+    }
+
+    #[test]
+    #[cfg(feature = "tamil")]
+    fn test_tamil() {
+        test(Tamil, "த-மிழ்-நா-டு");
+    }
+
+    #[test]
+    #[cfg(feature = "basque")]
+    fn test_basque() {
+        test(Basque, "eus-ka-ra");
+        test(Basque, "hiz-kun-tza");
+        test(Basque, "uni-ber-tsi-ta-tea");
+    }
+
+    #[test]
+    #[cfg(feature = "welsh")]
+    fn test_welsh() {
+        test(Welsh, "llyfr-gell");
+        test(Welsh, "Cym-raeg");
+    }
+
+    #[test]
+    #[cfg(feature = "irish")]
+    fn test_irish() {
+        test(Irish, "oll-scoil");
+        test(Irish, "leabh-ar-lann");
+    }
+
+    #[test]
+    #[cfg(feature = "romansh")]
+    fn test_romansh() {
+        test(Romansh, "ru-mantsch");
+        test(Romansh, "uni-ver-si-tad");
+    }
+
+    #[test]
+    #[cfg(feature = "occitan")]
+    fn test_occitan() {
+        test(Occitan, "oc-ci-tan");
+        test(Occitan, "bi-blio-tè-ca");
+    }
+
+    #[test]
+    #[cfg(feature = "friulian")]
+    fn test_friulian() {
+        test(Friulian, "fur-lan");
+        test(Friulian, "uni-ver-si-tât");
+    }
+
+    #[test]
+    #[cfg(feature = "piedmontese")]
+    fn test_piedmontese() {
+        test(Piedmontese, "pie-mon-tèis");
+        test(Piedmontese, "or-di-na-tor");
+    }
+
+    #[test]
+    #[cfg(feature = "interlingua")]
+    fn test_interlingua() {
+        test(Interlingua, "in-ter-lin-gua");
+        test(Interlingua, "bi-bli-o-the-ca");
+    }
+
+    #[test]
+    #[cfg(feature = "esperanto")]
+    fn test_esperanto() {
+        test(Esperanto, "uni-ver-si-ta-to");
+        test(Esperanto, "ĉiu-ta-ge");
+    }
+
+    #[test]
+    #[cfg(feature = "romanian")]
+    fn test_romanian() {
+        test(Romanian, "cal-cu-la-tor");
+        test(Romanian, "bi-bli-o-te-că");
+        test(Romanian, "ro-mâ-neș-te");
+    }
+
+    #[test]
+    #[cfg(feature = "latvian")]
+    fn test_latvian() {
+        test(Latvian, "uni-ver-si-tā-te");
+        test(Latvian, "lat-vie-šu");
+    }
+
+    #[test]
+    #[cfg(feature = "upper-sorbian")]
+    fn test_upper_sorbian() {
+        test(UpperSorbian, "bi-blio-te-ka");
+        test(UpperSorbian, "serb-šći-na");
+    }
+
+    #[test]
+    #[cfg(feature = "church-slavonic")]
+    fn test_church_slavonic() {
+        test(ChurchSlavonic, "бл҃го-че́-стїе");
+        test(ChurchSlavonic, "Бл҃го-че́-стїе");
+        test(ChurchSlavonic, "БЛ҃ГО-ЧЕ́-СТЇЕ");
+        test(ChurchSlavonic, "ᲂу҆-стре-мле́-нїе");
+        test(ChurchSlavonic, "Оу҆-стре-мле́-нїе");
+        test(ChurchSlavonic, "ОУ҆-СТРЕ-МЛЕ́-НЇЕ");
+
+        // Only a word-initial оу is read as the narrow-o digraph.
+        test(ChurchSlavonic, "за-ᲂу-ха");
+        test(ChurchSlavonic, "заоуха");
+    }
+
+    #[test]
+    #[cfg(feature = "armenian")]
+    fn test_armenian() {
+        test(Armenian, "հա-մա-կարգիչ");
+        test(Armenian, "գրա-դա-րան");
+        assert_eq!(Armenian.hyphen(), Some('\u{58A}'));
+    }
+
+    #[test]
+    #[cfg(feature = "ethiopic")]
+    fn test_ethiopic() {
+        test(Ethiopic, "አ-ማ-ር-ኛ");
+        test(Ethiopic, "ቤ-ተ-መ-ጻ-ሕ-ፍ-ት");
+        test(Ethiopic, "ሰ-ላ-ም፡ነ-ው");
+        assert_eq!(Lang::from_iso(*b"am"), Some(Ethiopic));
+    }
+
+    #[test]
+    #[cfg(feature = "coptic")]
+    fn test_coptic() {
+        test(Coptic, "ⲛⲟⲩ-ⲧⲉ");
+        test(Coptic, "ⲉⲕ-ⲕⲗⲏ-ⲥⲓⲁ");
+    }
+
+    #[test]
+    #[cfg(feature = "pali")]
+    fn test_pali() {
+        test(Pali, "dham-ma");
+        test(Pali, "saṅ-gho");
+        test(Pali, "nib-bā-na");
+    }
+
+    #[test]
+    #[cfg(feature = "indonesian")]
+    fn test_indonesian() {
+        test(Indonesian, "per-pus-ta-ka-an");
+        test(Indonesian, "kom-pu-ter");
+        test(Indonesian, "pe-ngem-bang-an");
+        assert_eq!(Lang::from_iso(*b"ms"), Some(Indonesian));
+    }
+
+    #[test]
+    #[cfg(feature = "thai")]
+    fn test_thai() {
+        test(Thai, "ภาษา-ไทย-เป็น-ภาษา-ที่-สวยงาม");
+        test(Thai, "ฉัน-กิน-ข้าว-ที่-ร้าน-อาหาร");
+        test(Thai, "สวัสดี-ครับ");
+        test(Thai, "abc-ไทย");
+        let bounded = hyphenate_bounded("ภาษาไทยเป็นภาษา", Thai, 5, 5);
+        assert_eq!(bounded.join("-"), "ภาษาไทย-เป็นภาษา");
+        assert_eq!(Thai.hyphen(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "english")]
+    fn test_soft_hyphens() {
+        assert_eq!(hyphenate("won\u{ad}derful", English).join("-"), "won\u{ad}-derful");
+        assert_eq!(
+            hyphenate("w\u{ad}onderful\u{ad}", English).join("-"),
+            "w\u{ad}-onderful\u{ad}"
+        );
+
+        // Soft hyphens without a letter on both sides are not honoured.
+        assert_eq!(hyphenate("\u{ad}", English).join("-"), "\u{ad}");
+        assert_eq!(hyphenate("\u{ad}\u{ad}", English).join("-"), "\u{ad}\u{ad}");
+        assert_eq!(hyphenate("\u{ad}won\u{ad}", English).join("-"), "\u{ad}won\u{ad}");
+        assert_eq!(hyphenate("won\u{ad}-der", English).len(), 1);
+        assert_eq!(
+            hyphenate("won\u{ad}\u{ad}der\u{ad}\u{ad}ful", English).join("|"),
+            "won\u{ad}\u{ad}|der\u{ad}\u{ad}|ful"
+        );
+
+        let stripped = |word| hyphenate_stripped(word, English, 2, 3).join("-");
+        assert_eq!(stripped("wonderful"), "won-der-ful");
+        assert_eq!(stripped("wo\u{ad}nderful"), "wo\u{ad}n-der-ful");
+        assert_eq!(
+            stripped("won\u{ad}\u{ad}derful\u{ad}"),
+            "won\u{ad}\u{ad}-der-ful\u{ad}"
+        );
+        assert_eq!(stripped("\u{ad}wonderful"), "\u{ad}won-der-ful");
+        assert_eq!(stripped("\u{ad}"), "\u{ad}");
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "english"))]
+    fn test_explain() {
+        let explanation = super::hyphenate_explain("Extensive", English);
+        assert_eq!(explanation.dotted, ".extensive.");
+        let patterns: alloc::vec::Vec<_> =
+            explanation.matches.iter().map(|m| m.pattern.as_str()).collect();
+        assert_eq!(patterns, ["x1t2", "2n1s2", "1siv", "2iv", "4ve."]);
+        assert_eq!(explanation.matches[1].start, 5);
+        assert_eq!(explanation.matches[1].levels, [(5, 2), (6, 1), (7, 2)]);
+        assert_eq!(explanation.levels, [0, 0, 0, 1, 2, 2, 1, 2, 4, 0, 0, 0]);
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "english"))]
+    fn test_overlay() {
+        use super::{Overlay, Trie};
+
+        // Overlay levels only win where they are higher than the built-in ones.
+        let overlay = Overlay::new(English, Trie::new(["x0t", "e5n"]));
+        assert_eq!(overlay.hyphenate("extension").join("-"), "ex-te-n-sion");
+        assert_eq!(overlay.hyphenate("motion").join("-"), "mo-tion");
+        assert_eq!(overlay.hyphenate_bounded("tender", 3, 3).join("-"), "ten-der");
+        assert_eq!(overlay.hyphenate("ten\u{ad}der").join("-"), "ten\u{ad}-der");
+        assert_eq!(hyphenate("tender", English).join("-"), "ten-der");
+        assert_eq!(overlay.trie().patterns().count(), 2);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_trie_errors() {
+        use super::{Trie, TrieError};
+
+        let levels = "1a".repeat(16);
+        assert_eq!(Trie::try_new([&levels]), Err(TrieError::TooManyLevels(levels)));
+        assert!(Trie::try_new([&"1a".repeat(15)]).is_ok());
+
+        let gap = alloc::format!("1{}1", "ж".repeat(13));
+        assert_eq!(Trie::try_new([&gap]), Err(TrieError::TooLongGap(gap)));
+        assert!(Trie::try_new([&alloc::format!("1{}1", "ж".repeat(12))]).is_ok());
+
+        // Each pattern needs its own levels, which don't fit after a while.
+        let many = (0..3000).map(|i: u32| {
+            let mut pattern = alloc::string::String::new();
+            for d in alloc::format!("{i:04}").bytes() {
+                pattern.push(char::from(d));
+                pattern.push(char::from(d - b'0' + b'a'));
+            }
+            pattern
+        });
+        assert_eq!(Trie::try_new(many), Err(TrieError::TooLarge));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_trie_four_byte() {
+        use super::Trie;
+
+        // Four-byte chars in patterns are walked byte by byte like others.
+        let trie = Trie::new(["𝔵1𝔱", "2😀"]);
+        assert_eq!(trie.hyphenate_bounded("𝔢𝔵𝔱𝔢", 2, 2).join("-"), "𝔢𝔵-𝔱𝔢");
+        assert_eq!(trie.hyphenate_bounded("𝔢𝔵𝔱😀", 2, 1).join("-"), "𝔢𝔵-𝔱😀");
+        assert_eq!(trie.patterns().count(), 2);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_parse_tex_unterminated() {
+        use super::parse_tex;
+        assert_eq!(parse_tex(r"\patterns{ .un1 1tion"), [".un1", "1tion"]);
+        assert_eq!(parse_tex(r"\patterns{"), [] as [&str; 0]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_dic() {
+        use super::{Dic, Trie};
+
+        let text = "ISO8859-1\n\
+            % A comment.\n\
+            LEFTHYPHENMIN 2\n\
+            RIGHTHYPHENMIN x\n\
+            COMPOUNDLEFTHYPHENMIN 3\n\
+            NOHYPHEN -,'\n\
+            1-1\n\
+            NEXTLEVEL\n\
+            # Another comment.\n\
+            \x20 c1k/k=k,1,2 \n\
+            a1b\n\
+            2ab0\n";
+
+        let dic = Dic::parse(text);
+        assert_eq!(dic.charset, "ISO8859-1");
+        assert_eq!(dic.left_min, Some(2));
+        assert_eq!(dic.right_min, None);
+        assert_eq!(dic.compound_left_min, Some(3));
+        assert_eq!(dic.compound_right_min, None);
+        assert_eq!(dic.no_hyphen, ["-", "'"]);
+        assert_eq!(dic.patterns, ["1-1", "c1k", "2a1b0"]);
+        assert_eq!(dic.nonstandard, ["c1k/k=k,1,2"]);
+        assert_eq!(Trie::new(&dic.patterns).patterns().count(), 3);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_patgen() {
+        use super::{Level, Patgen};
+
+        let words =
+            ["Hy-phen-ation", "ex-ten-sive", "ex-ten-sion", "pen-sion", "tension"];
+        let mut patgen = Patgen::new(words).bounds(2, 2);
+        let level = |good_weight, bad_weight, threshold| Level {
+            min_len: 1,
+            max_len: 4,
+            good_weight,
+            bad_weight,
+            threshold,
+        };
+
+        let first = patgen.learn(level(1, 1, 1));
+        assert_eq!((first.level, first.missed), (1, 0));
+        assert!(first.wrong > 0);
+        let second = patgen.learn(level(1, 1, 1));
+        assert_eq!((second.level, second.correct), (2, 7));
+        assert_eq!((second.wrong, second.missed), (0, 0));
+        assert_eq!(patgen.stats(), second);
+
+        let trie = patgen.trie().unwrap();
+        for word in words {
+            let plain = word.replace('-', "");
+            let syllables = trie.hyphenate_bounded(&plain, 2, 2);
+            assert_eq!(syllables.join("-").to_lowercase(), word.to_lowercase());
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "english"))]
+    fn test_correct() {
+        use super::{correct, Overlay, Trie};
+
+        let fixes = ["un-icorn", "ta-ble", "u-nion"];
+        let reference = ["uniform", "unicorn", "tables", "Table"];
+        let correction = correct(English, &fixes, &reference);
+        assert_eq!(correction.unfixed, ["u-nion"]);
+        assert_eq!(correction.collateral, [("Table".into(), "Ta-ble".into())]);
+
+        let overlay = Overlay::new(English, Trie::new(&correction.patterns));
+        assert_eq!(overlay.hyphenate("unicorns").join("-"), "un-icorns");
+        assert_eq!(overlay.hyphenate("uniform").join("-"), "uni-form");
+        assert_eq!(overlay.hyphenate("table").join("-"), "ta-ble");
+        assert_eq!(overlay.hyphenate("tables").join("-"), "ta-bles");
+        assert_eq!(overlay.hyphenate("union").join("-"), "union");
+
+        // Patterns with too many bytes between two levels can't be encoded.
+        let fixes = ["electroencephalographically-ex"];
+        let reference = ["reelectroencephalographicallyex"];
+        let correction = correct(English, &fixes, &reference);
+        assert_eq!(correction.unfixed, fixes);
+        assert!(correction.patterns.is_empty());
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "english"))]
+    fn test_evaluate() {
+        use super::evaluate;
+
+        // The hyphen in `a-bout` is within the left bound of English.
+        let eval = evaluate(English, ["a-bout", "", "  ta-ble  ", "-"]);
+        assert_eq!(eval.words, 3);
+        assert_eq!(eval.exact, 2);
+        assert_eq!((eval.correct, eval.wrong, eval.missed), (0, 0, 1));
+        assert_eq!(eval.precision(), 1.0);
+        assert_eq!(eval.recall(), 0.0);
+        assert_eq!(eval.mismatches.len(), 1);
+        assert_eq!(eval.mismatches[0].actual, "table");
+    }
+
+    #[test]
+    fn test_graphemes() {
+        let ends = |text| super::grapheme::ends(text).collect::<alloc::vec::Vec<_>>();
+        assert_eq!(ends(""), []);
+        assert_eq!(ends("e\u{301}x"), [3, 4]);
+        assert_eq!(ends("\r\n\u{AD}a"), [2, 4, 5]);
+        assert_eq!(ends("👨\u{200D}👩\u{200D}👧x"), [18, 19]);
+        assert_eq!(ends("❤\u{FE0F}x"), [6, 7]);
+        assert_eq!(ends("🇩🇪🇫🇷🇮"), [8, 16, 20]);
+        assert_eq!(ends("\u{1112}\u{1161}\u{11AB}한"), [9, 12]);
+        assert_eq!(ends("क्षा"), [12]);
+        assert_eq!(ends("க்ஷ"), [6, 9]);
+    }
+
+    #[test]
+    fn test_clusters() {
+        // The fast path must agree with the full segmentation.
+        for text in ["", "wonderful", "©\u{AD}ß\tĳ", "a\r\nb", "e\u{301}x", "क्षा"]
+        {
+            let clusters = Clusters::new(text);
+            let starts = clusters.starts().collect::<alloc::vec::Vec<_>>();
+            let ends = super::grapheme::ends(text).collect::<alloc::vec::Vec<_>>();
+            assert_eq!(starts, ends[..ends.len().saturating_sub(1)]);
+        }
+        assert!(Clusters::new("©\u{AD}ß\tĳ").starts.is_none());
+        assert!(Clusters::new("a\r\nb").starts.is_some());
+    }
+
+    #[test]
+    #[cfg(feature = "english")]
+    fn test_cluster_bounds() {
+        // The `ü` consists of two chars, but counts as one.
+        let syllables = hyphenate_bounded("wonderfu\u{308}l", English, 2, 4);
+        assert_eq!(syllables.join("-"), "won-derfu\u{308}l");
+        let syllables = hyphenate_bounded("wonderfu\u{308}l", English, 2, 3);
+        assert_eq!(syllables.join("-"), "won-der-fu\u{308}l");
+    }
+
+    #[test]
+    fn test_syllabifier() {
+        let swahili = Syllabifier::new("aeiou").onsets(&["mb", "nd", "ng", "ny", "sw"]);
+        assert_eq!(swahili.syllabify("kiswahili").join("-"), "ki-swa-hi-li");
+        assert_eq!(swahili.syllabify("kiatu").join("-"), "ki-a-tu");
+        assert_eq!(swahili.syllabify("Ninakupenda").join("-"), "Ni-na-ku-pe-nda");
+
+        let maori = Syllabifier::new("aeiouāēīōū").onsets(&["ng", "wh"]);
+        assert_eq!(maori.syllabify("whakapapa").join("-"), "wha-ka-pa-pa");
+        assert_eq!(maori.syllabify("MĀORI").join("-"), "MĀ-O-RI");
+
+        let tagalog = Syllabifier::new("aeiou").onsets(&["ng"]).bounds(1, 1);
+        assert_eq!(tagalog.syllabify("maganda").join("-"), "ma-gan-da");
+        assert_eq!(tagalog.syllabify("kaibigan").join("-"), "ka-i-bi-gan");
+        assert_eq!(tagalog.syllabify("ngayon").join("-"), "nga-yon");
+        assert_eq!(tagalog.syllabify("e\u{301}ksperto").join("-"), "e\u{301}ks-per-to");
+    }
+
+    #[test]
+    #[cfg(feature = "korean")]
+    fn test_korean() {
+        test(Korean, "한-국-어");
+        test(Korean, "대-한-민-국");
+        test(Korean, "한-국-어2024");
+        test(Korean, "\u{1112}\u{1161}\u{11AB}-\u{1100}\u{116E}\u{11A8}");
+        assert_eq!(Korean.hyphen(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "cherokee")]
+    fn test_cherokee() {
+        test(Cherokee, "Ꮳ-Ꮃ-Ꭹ");
+        test(Cherokee, "ᏍᎦ-Ꮪ-Ꭹ");
+    }
+
+    #[test]
+    #[cfg(feature = "canadian-syllabics")]
+    fn test_canadian_syllabics() {
+        test(CanadianSyllabics, "ᐃ-ᓄᒃ-ᑎ-ᑐᑦ");
+        test(CanadianSyllabics, "ᓄ-ᓇ-ᕗᑦ");
+        assert_eq!(Lang::from_iso(*b"iu"), Some(CanadianSyllabics));
+    }
+
+    #[test]
+    #[cfg(feature = "lao")]
+    fn test_lao() {
+        test(Lao, "ພາສາ-ລາວ-ເປັນ-ພາສາ-ທີ່-ສວຍງາມ");
+    }
+
+    #[test]
+    #[cfg(feature = "khmer")]
+    fn test_khmer() {
+        test(Khmer, "ភាសាខ្មែរ-ជា-ភាសាផ្លូវការ");
+    }
+
+    #[test]
+    #[cfg(all(feature = "english", feature = "coptic"))]
+    fn test_multibyte() {
+        // Three-byte chars that are part of the patterns.
+        test(Coptic, "ⲣⲱ-ⲙⲉ");
+
+        // Four-byte chars that the trie has no transitions for must not
+        // disturb matching after them.
+        test(English, "ex😀ten-sive");
+        test(English, "𝔢𝔵𝔱𝔢𝔫𝔰𝔦𝔳𝔢");
     }
 }
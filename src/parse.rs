@@ -0,0 +1,255 @@
+//! Parsing hyphenation pattern files.
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+
+/// Parse the patterns of a TeX pattern file like `hyph-en-us.tex`.
+///
+/// Returns the patterns in the `\patterns{..}` blocks in order. Comments and
+/// hyphenation exceptions are skipped, so the exceptions of the file are lost.
+/// Patterns are returned as written: When they are compiled into a
+/// [`Trie`](crate::Trie), the later of two patterns with the same letters wins.
+///
+/// This is only available when the `alloc` feature is enabled.
+///
+/// # Example
+/// ```
+/// # use hypher::{parse_tex, Trie};
+/// let tex = r"\patterns{ % Some patterns.
+///   .un1 1tion
+/// }";
+/// assert_eq!(parse_tex(tex), [".un1", "1tion"]);
+/// let trie = Trie::new(parse_tex(tex));
+/// ```
+pub fn parse_tex(tex: &str) -> Vec<&str> {
+    let mut patterns = vec![];
+    let mut s = Scanner(tex);
+    while let Some(c) = s.eat() {
+        match c {
+            '%' => {
+                s.eat_while(|c| c != '\n');
+            }
+            '\\' if s.eat_if("patterns{") => loop {
+                let pat = s.eat_while(|c| c != '}' && c != '%' && !c.is_whitespace());
+                if !pat.is_empty() {
+                    patterns.push(pat);
+                }
+                match s.eat() {
+                    Some('}') | None => break,
+                    Some('%') => s.eat_while(|c| c != '\n'),
+                    _ => s.eat_while(char::is_whitespace),
+                };
+            },
+            _ => {}
+        }
+    }
+    patterns
+}
+
+/// A hyphenation dictionary in the format of libhyphen, which LibreOffice and
+/// Hunspell use for their `hyph_*.dic` files.
+///
+/// The first line of such a file names its charset. The text must already be
+/// decoded from it, as only UTF-8 is supported. After that, each line holds
+/// either a pattern, a keyword with its value or a comment starting with `%`
+/// or `#`.
+///
+/// Parsing is lossy for two kinds of content that hypher can't represent:
+/// - hypher only breaks words, without changing letters, so for nonstandard
+///   entries like `c1k/k=k,1,2` only the plain break of the pattern in front
+///   of the slash is kept. The full entries are listed in
+///   [`nonstandard`](Self::nonstandard).
+/// - libhyphen first breaks compound words with the patterns before a
+///   `NEXTLEVEL` keyword and then their parts with the ones after it. Here,
+///   the patterns of all levels are merged into one set, as if they were
+///   matched along with each other.
+///
+/// Formatting a dictionary writes it back out in this format, with the
+/// merged patterns and without the nonstandard entries.
+///
+/// This is only available when the `alloc` feature is enabled.
+///
+/// # Example
+/// ```
+/// # use hypher::{Dic, Trie};
+/// let dic = Dic::parse("UTF-8\nLEFTHYPHENMIN 2\n.un1\n1tion\n");
+/// assert_eq!(dic.left_min, Some(2));
+/// assert_eq!(dic.patterns, [".un1", "1tion"]);
+/// let trie = Trie::new(&dic.patterns);
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Dic {
+    /// The charset named in the first line.
+    pub charset: String,
+    /// The minimum number of chars before a break (`LEFTHYPHENMIN`).
+    pub left_min: Option<usize>,
+    /// The minimum number of chars after a break (`RIGHTHYPHENMIN`).
+    pub right_min: Option<usize>,
+    /// The minimum number of chars before a break within a part of a compound
+    /// word (`COMPOUNDLEFTHYPHENMIN`).
+    pub compound_left_min: Option<usize>,
+    /// The minimum number of chars after a break within a part of a compound
+    /// word (`COMPOUNDRIGHTHYPHENMIN`).
+    pub compound_right_min: Option<usize>,
+    /// Chars and strings next to which no break may occur (`NOHYPHEN`).
+    pub no_hyphen: Vec<String>,
+    /// The patterns, with those of all levels merged.
+    pub patterns: Vec<String>,
+    /// The nonstandard entries like `c1k/k=k,1,2`, which are only kept as
+    /// their plain break in the patterns.
+    pub nonstandard: Vec<String>,
+}
+
+impl Dic {
+    /// Parse the text of a libhyphen dictionary.
+    ///
+    /// Like libhyphen, this is lenient: keywords with a malformed value are
+    /// skipped. Patterns with the same letters, e.g. from different levels,
+    /// are merged by taking the higher level at each position. As described
+    /// [above](Self), the level structure and the replacements of nonstandard
+    /// entries are lost.
+    pub fn parse(text: &str) -> Self {
+        let mut lines = text.lines();
+        let mut dic = Self {
+            charset: lines.next().unwrap_or_default().trim().into(),
+            ..Self::default()
+        };
+
+        let mut seen: BTreeMap<String, usize> = BTreeMap::new();
+        for line in lines.map(str::trim) {
+            if line.is_empty() || line.starts_with(['%', '#']) {
+                continue;
+            }
+
+            let (keyword, value) =
+                line.split_once(char::is_whitespace).unwrap_or((line, ""));
+            let value = value.trim();
+            let slot = match keyword {
+                "LEFTHYPHENMIN" => &mut dic.left_min,
+                "RIGHTHYPHENMIN" => &mut dic.right_min,
+                "COMPOUNDLEFTHYPHENMIN" => &mut dic.compound_left_min,
+                "COMPOUNDRIGHTHYPHENMIN" => &mut dic.compound_right_min,
+                "NOHYPHEN" => {
+                    let items = value.split(',').filter(|item| !item.is_empty());
+                    dic.no_hyphen.extend(items.map(Into::into));
+                    continue;
+                }
+                "NEXTLEVEL" => continue,
+                _ => {
+                    // Drop the replacement of a nonstandard entry.
+                    let pattern = keyword.split('/').next().unwrap_or_default();
+                    if pattern.len() < keyword.len() {
+                        dic.nonstandard.push(keyword.into());
+                    }
+                    let letters: String =
+                        pattern.chars().filter(|c| !c.is_ascii_digit()).collect();
+                    if let Some(&idx) = seen.get(&letters) {
+                        let merged = merge(&dic.patterns[idx], pattern);
+                        dic.patterns[idx] = merged;
+                    } else {
+                        seen.insert(letters, dic.patterns.len());
+                        dic.patterns.push(pattern.into());
+                    }
+                    continue;
+                }
+            };
+
+            if let Ok(value) = value.parse() {
+                *slot = Some(value);
+            }
+        }
+
+        dic
+    }
+}
+
+impl Display for Dic {
+    /// Write the dictionary out in the format it is parsed from.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        writeln!(f, "{}", self.charset)?;
+        let keywords = [
+            ("LEFTHYPHENMIN", self.left_min),
+            ("RIGHTHYPHENMIN", self.right_min),
+            ("COMPOUNDLEFTHYPHENMIN", self.compound_left_min),
+            ("COMPOUNDRIGHTHYPHENMIN", self.compound_right_min),
+        ];
+        for (keyword, value) in keywords {
+            if let Some(value) = value {
+                writeln!(f, "{keyword} {value}")?;
+            }
+        }
+        if !self.no_hyphen.is_empty() {
+            writeln!(f, "NOHYPHEN {}", self.no_hyphen.join(","))?;
+        }
+        for pattern in &self.patterns {
+            writeln!(f, "{pattern}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Merge two patterns with the same letters by taking the higher level at
+/// each position.
+fn merge(a: &str, b: &str) -> String {
+    let (letters, mut levels) = split(a);
+    for (slot, level) in levels.iter_mut().zip(split(b).1) {
+        *slot = (*slot).max(level);
+    }
+
+    let mut merged = String::new();
+    for (i, c) in letters.char_indices() {
+        merged.extend(levels[i].map(|level| char::from(b'0' + level)));
+        merged.push(c);
+    }
+    merged.extend(levels[letters.len()].map(|level| char::from(b'0' + level)));
+    merged
+}
+
+/// Split a pattern into its letters and the level, if any, before each byte of
+/// them and at their end.
+fn split(pattern: &str) -> (String, Vec<Option<u8>>) {
+    let mut letters = String::new();
+    let mut levels = vec![None];
+    for c in pattern.chars() {
+        if c.is_ascii_digit() {
+            *levels.last_mut().unwrap() = Some(c as u8 - b'0');
+        } else {
+            letters.push(c);
+            levels.resize(letters.len() + 1, None);
+        }
+    }
+    (letters, levels)
+}
+
+struct Scanner<'a>(&'a str);
+
+impl<'a> Scanner<'a> {
+    fn eat(&mut self) -> Option<char> {
+        let mut chars = self.0.chars();
+        let c = chars.next();
+        self.0 = chars.as_str();
+        c
+    }
+
+    fn eat_if(&mut self, pat: &str) -> bool {
+        let matches = self.0.starts_with(pat);
+        if matches {
+            self.0 = &self.0[pat.len()..];
+        }
+        matches
+    }
+
+    fn eat_while(&mut self, f: fn(char) -> bool) -> &'a str {
+        let mut offset = 0;
+        let mut chars = self.0.chars();
+        while chars.next().map_or(false, f) {
+            offset = self.0.len() - chars.as_str().len();
+        }
+        let head = &self.0[..offset];
+        self.0 = &self.0[offset..];
+        head
+    }
+}
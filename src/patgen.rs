@@ -0,0 +1,274 @@
+//! Learning hyphenation patterns from hyphenated words.
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::{lowercase, Trie, TrieError};
+
+/// Learns Liang hyphenation patterns from a list of hyphenated words, like
+/// TeX's `patgen`.
+///
+/// Patterns are learned level by level. Odd levels allow breaks and thus
+/// find missed hyphens, even levels forbid breaks and thus fix wrong ones.
+/// For each level, candidate patterns are tried from the shortest to the
+/// longest length. A candidate is good for each position where it would fix
+/// the current result and bad for each position where it would spoil it. It
+/// is kept if its weighted good count exceeds its weighted bad count by at
+/// least the threshold.
+///
+/// This is only available when the `alloc` feature is enabled.
+///
+/// # Example
+/// ```
+/// # use hypher::{Level, Patgen, Trie};
+/// let words = ["hy-phen-ation", "ex-ten-sive", "ex-ten-sion", "pen-sion"];
+/// let mut patgen = Patgen::new(words).bounds(2, 2);
+/// let level = Level { min_len: 2, max_len: 4, good_weight: 1, bad_weight: 1, threshold: 1 };
+/// let stats = patgen.learn(level);
+/// assert_eq!(stats.missed, 0);
+/// let trie = patgen.trie().unwrap();
+/// assert_eq!(trie, Trie::new(patgen.patterns()));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Patgen {
+    /// The dotted words with whether there is a hyphen before each char.
+    words: Vec<(Vec<char>, Vec<bool>)>,
+    /// The patterns learned so far, with the level before each of their
+    /// letters and at their end.
+    patterns: BTreeMap<Vec<char>, Vec<u8>>,
+    /// The length of the longest pattern.
+    max_len: usize,
+    /// The number of levels learned so far.
+    level: u8,
+    left_min: usize,
+    right_min: usize,
+}
+
+/// The parameters for learning one level of patterns with a [`Patgen`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Level {
+    /// The number of letters, including dots, of the shortest patterns.
+    pub min_len: usize,
+    /// The number of letters, including dots, of the longest patterns.
+    pub max_len: usize,
+    /// The weight of a position where a pattern fixes the result.
+    pub good_weight: usize,
+    /// The weight of a position where a pattern spoils the result.
+    pub bad_weight: usize,
+    /// How much the weighted good count must exceed the weighted bad count.
+    pub threshold: usize,
+}
+
+/// How well the patterns learned so far hyphenate the words.
+///
+/// This struct is returned by [`Patgen::learn`] and [`Patgen::stats`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Stats {
+    /// The number of levels learned so far.
+    pub level: u8,
+    /// The number of patterns learned so far.
+    pub patterns: usize,
+    /// The number of hyphens that were found.
+    pub correct: usize,
+    /// The number of breaks that were found, but are no hyphens.
+    pub wrong: usize,
+    /// The number of hyphens that were not found.
+    pub missed: usize,
+}
+
+impl Patgen {
+    /// Create a pattern generator for words hyphenated with `-`, like
+    /// `hy-phen-ation`.
+    ///
+    /// Forbids breaking between two chars to each side of the word by default.
+    pub fn new<I>(words: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let mut parsed = vec![];
+        for word in words {
+            let mut chars = vec!['.'];
+            let mut hyphens = vec![false];
+            let mut hyphen = false;
+            for c in word.as_ref().chars() {
+                if c == '-' {
+                    hyphen = true;
+                } else {
+                    chars.push(lowercase(c));
+                    hyphens.push(hyphen && chars.len() > 2);
+                    hyphen = false;
+                }
+            }
+            chars.push('.');
+            hyphens.push(false);
+
+            if chars.len() > 2 {
+                parsed.push((chars, hyphens));
+            }
+        }
+
+        Self {
+            words: parsed,
+            patterns: BTreeMap::new(),
+            max_len: 0,
+            level: 0,
+            left_min: 2,
+            right_min: 2,
+        }
+    }
+
+    /// Set the number of chars to each side between which breaking is
+    /// forbidden.
+    ///
+    /// Hyphens within these bounds are neither learned nor counted.
+    pub fn bounds(mut self, left_min: usize, right_min: usize) -> Self {
+        self.left_min = left_min.max(1);
+        self.right_min = right_min.max(1);
+        self
+    }
+
+    /// Learn the next level of patterns.
+    ///
+    /// Returns how well the patterns hyphenate the words afterwards.
+    ///
+    /// # Panics
+    /// Panics if nine levels were already learned.
+    pub fn learn(&mut self, params: Level) -> Stats {
+        assert!(self.level < 9, "too many levels");
+        self.level += 1;
+        let level = self.level;
+        let hyphenating = level % 2 == 1;
+
+        for len in params.min_len.max(1)..=params.max_len {
+            // Count for each candidate pattern and position of its level the
+            // good and bad positions.
+            let mut counts: BTreeMap<(Vec<char>, usize), (usize, usize)> =
+                BTreeMap::new();
+            for (chars, hyphens) in &self.words {
+                if chars.len() < len {
+                    continue;
+                }
+
+                let levels = self.levels(chars);
+                for k in self.allowed(chars) {
+                    // Positions that already have the desired parity are fine.
+                    if (levels[k] % 2 == 1) == hyphenating {
+                        continue;
+                    }
+
+                    let good = hyphens[k] == hyphenating;
+                    let first = k.saturating_sub(len);
+                    let last = k.min(chars.len() - len);
+                    for start in first..=last {
+                        let window = chars[start..start + len].to_vec();
+                        let count = counts.entry((window, k - start)).or_default();
+                        if good {
+                            count.0 += 1;
+                        } else {
+                            count.1 += 1;
+                        }
+                    }
+                }
+            }
+
+            // Keep the patterns that do enough good.
+            for ((window, dot), (good, bad)) in counts {
+                if good * params.good_weight >= bad * params.bad_weight + params.threshold
+                {
+                    self.insert(window, dot, level);
+                }
+            }
+        }
+
+        self.stats()
+    }
+
+    /// Evaluate the patterns learned so far on the words.
+    pub fn stats(&self) -> Stats {
+        let mut stats = Stats {
+            level: self.level,
+            patterns: self.patterns.len(),
+            correct: 0,
+            wrong: 0,
+            missed: 0,
+        };
+
+        for (chars, hyphens) in &self.words {
+            let levels = self.levels(chars);
+            for k in self.allowed(chars) {
+                match (hyphens[k], levels[k] % 2 == 1) {
+                    (true, true) => stats.correct += 1,
+                    (false, true) => stats.wrong += 1,
+                    (true, false) => stats.missed += 1,
+                    (false, false) => {}
+                }
+            }
+        }
+
+        stats
+    }
+
+    /// The patterns learned so far, like `.a1bc2d`, sorted by their letters.
+    pub fn patterns(&self) -> Vec<String> {
+        self.patterns
+            .iter()
+            .map(|(letters, levels)| {
+                let mut pattern = String::new();
+                for (&c, &level) in letters.iter().zip(levels) {
+                    if level > 0 {
+                        pattern.push(char::from(b'0' + level));
+                    }
+                    pattern.push(c);
+                }
+                if levels[letters.len()] > 0 {
+                    pattern.push(char::from(b'0' + levels[letters.len()]));
+                }
+                pattern
+            })
+            .collect()
+    }
+
+    /// Compile the patterns learned so far into a trie.
+    ///
+    /// Fails if a pattern can't be encoded, e.g. because long patterns of
+    /// multi-byte chars have more than 24 bytes between two levels.
+    pub fn trie(&self) -> Result<Trie, TrieError> {
+        Trie::try_new(self.patterns())
+    }
+
+    /// Add a level before the char at `dot` of a pattern.
+    fn insert(&mut self, letters: Vec<char>, dot: usize, level: u8) {
+        self.max_len = self.max_len.max(letters.len());
+        let len = letters.len();
+        let levels = self.patterns.entry(letters).or_insert_with(|| vec![0; len + 1]);
+        levels[dot] = levels[dot].max(level);
+    }
+
+    /// Compute the level before each char of a dotted word.
+    fn levels(&self, chars: &[char]) -> Vec<u8> {
+        let mut levels = vec![0; chars.len() + 1];
+        for start in 0..chars.len() {
+            let longest = self.max_len.min(chars.len() - start);
+            for len in 1..=longest {
+                if let Some(found) = self.patterns.get(&chars[start..start + len]) {
+                    for (slot, &level) in levels[start..].iter_mut().zip(found) {
+                        *slot = (*slot).max(level);
+                    }
+                }
+            }
+        }
+        levels
+    }
+
+    /// The positions in a dotted word at which breaking is allowed.
+    fn allowed(&self, chars: &[char]) -> core::ops::Range<usize> {
+        // Before the char at `k`, there are `k - 1` letters and after it
+        // `chars.len() - 1 - k`.
+        let start = 1 + self.left_min;
+        let end = chars.len().saturating_sub(self.right_min);
+        start..end.max(start)
+    }
+}
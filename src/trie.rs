@@ -0,0 +1,672 @@
+//! Compiling patterns into tries at runtime and listing the patterns of a
+//! trie.
+
+use alloc::collections::btree_map::{BTreeMap, Entry};
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::iter::FusedIterator;
+
+use super::{
+    compute_levels, from_be_bytes, soft_hyphen_levels, Clusters, Dic, State, Syllables,
+    SOFT_HYPHEN,
+};
+
+/// Why patterns could not be compiled into a [`Trie`].
+///
+/// This is only available when the `alloc` feature is enabled.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum TrieError {
+    /// A pattern has more than 15 levels.
+    TooManyLevels(String),
+    /// A pattern has more than 24 bytes of letters between two levels.
+    TooLongGap(String),
+    /// The trie got too large to encode.
+    TooLarge,
+}
+
+impl Display for TrieError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::TooManyLevels(pattern) => {
+                write!(f, "pattern {pattern:?} has more than 15 levels")
+            }
+            Self::TooLongGap(pattern) => {
+                write!(f, "pattern {pattern:?} has more than 24 bytes between two levels")
+            }
+            Self::TooLarge => f.write_str("trie is too large to encode"),
+        }
+    }
+}
+
+/// Hyphenation patterns compiled into a trie at runtime.
+///
+/// The trie has the same encoding as the ones embedded for each
+/// [`Lang`](crate::Lang) in `tries/*.bin`.
+///
+/// This is only available when the `alloc` feature is enabled.
+///
+/// # Example
+/// ```
+/// # use hypher::Trie;
+/// let trie = Trie::new(["1tion", ".un1"]);
+/// let mut patterns: Vec<_> = trie.patterns().collect();
+/// patterns.sort();
+/// assert_eq!(patterns, [".un1", "1tion"]);
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct Trie {
+    data: Vec<u8>,
+}
+
+impl Trie {
+    /// Compile patterns like `.a1bc2d` into a trie.
+    ///
+    /// Digits are the levels between the letters and a dot marks the start
+    /// or end of a word. Patterns are matched against lowercased words, so
+    /// their letters should be lowercase. If two patterns have the same
+    /// letters, the later one wins.
+    ///
+    /// The order of the patterns doesn't matter otherwise: The same patterns
+    /// always give the same trie. Compiling the [patterns](Self::patterns) of
+    /// a trie thus reproduces it byte by byte.
+    ///
+    /// # Panics
+    /// Panics if a pattern has more than 15 levels or more than 24 bytes of
+    /// letters between two levels, or if the trie gets too large to encode.
+    /// Use [`try_new`](Self::try_new) to handle these cases.
+    pub fn new<I>(patterns: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        Self::try_new(patterns).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Compile patterns like [`new`](Self::new), but return an error instead
+    /// of panicking if they can't be encoded.
+    ///
+    /// # Example
+    /// ```
+    /// # use hypher::{Trie, TrieError};
+    /// assert!(Trie::try_new(["1tion", ".un1"]).is_ok());
+    /// assert_eq!(
+    ///     Trie::try_new(["1a1b1c1d1e1f1g1h1i1j1k1l1m1n1o1p"]),
+    ///     Err(TrieError::TooManyLevels("1a1b1c1d1e1f1g1h1i1j1k1l1m1n1o1p".into())),
+    /// );
+    /// ```
+    pub fn try_new<I>(patterns: I) -> Result<Self, TrieError>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let data = compile(patterns)?;
+        Ok(Self { data })
+    }
+
+    /// Load a trie from the bytes returned by [`as_bytes`](Self::as_bytes),
+    /// e.g. after storing them in a file.
+    ///
+    /// Returns `None` unless the bytes are exactly what [`Trie::new`] produces
+    /// for some patterns. To check this, the trie is decompiled and compiled
+    /// again, which takes about as long as compiling it in the first place.
+    /// Tries that are very small for the number of their patterns are
+    /// rejected, too.
+    ///
+    /// # Example
+    /// ```
+    /// # use hypher::Trie;
+    /// let trie = Trie::new(["1tion", ".un1"]);
+    /// assert_eq!(Trie::from_bytes(trie.as_bytes().to_vec()), Some(trie));
+    /// assert_eq!(Trie::from_bytes(vec![0, 0, 0, 4, 7]), None);
+    /// ```
+    pub fn from_bytes(data: Vec<u8>) -> Option<Self> {
+        let patterns = decompile(&data)?;
+        let compiled = compile(patterns).ok()?;
+        (compiled == data).then_some(Self { data })
+    }
+
+    /// The encoded trie.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Segment a word into syllables with just the patterns of this trie, but
+    /// forbid breaking between the given number of grapheme clusters to each
+    /// side.
+    ///
+    /// Like [`hyphenate_bounded`](crate::hyphenate_bounded), this only
+    /// breaks after soft hyphens if the word contains any.
+    ///
+    /// # Example
+    /// ```
+    /// # use hypher::Trie;
+    /// let trie = Trie::new(["n1s", "x1t"]);
+    /// assert_eq!(trie.hyphenate_bounded("extensive", 2, 2).join("-"), "ex-ten-sive");
+    /// ```
+    pub fn hyphenate_bounded<'a>(
+        &self,
+        word: &'a str,
+        left_min: usize,
+        right_min: usize,
+    ) -> Syllables<'a> {
+        let clusters = Clusters::new(word);
+        let levels = if word.contains(SOFT_HYPHEN) {
+            soft_hyphen_levels(word)
+        } else {
+            compute_levels(&clusters, self.root(), None, left_min, right_min)
+        };
+
+        Syllables::new(&clusters, levels)
+    }
+
+    /// The patterns from which the trie was built, in no particular order.
+    pub fn patterns(&self) -> Patterns<'_> {
+        Patterns::new(self.root())
+    }
+
+    /// Write the patterns of the trie out as a TeX pattern file.
+    ///
+    /// [Parsing](crate::parse_tex) the file and compiling its patterns gives
+    /// back the same trie.
+    ///
+    /// # Example
+    /// ```
+    /// # use hypher::{parse_tex, Lang, Trie};
+    /// let trie = Trie::new(Lang::English.patterns());
+    /// let tex = trie.to_tex();
+    /// assert_eq!(Trie::new(parse_tex(&tex)), trie);
+    /// ```
+    pub fn to_tex(&self) -> String {
+        let mut tex =
+            String::from("% Hyphenation patterns exported by hypher.\n\\patterns{\n");
+        for pattern in self.patterns() {
+            tex.push_str(&pattern);
+            tex.push('\n');
+        }
+        tex.push_str("}\n");
+        tex
+    }
+
+    /// A libhyphen dictionary in UTF-8 with the patterns of the trie.
+    ///
+    /// Set its bounds and format it to get the text of a `hyph_*.dic` file.
+    /// [Parsing](Dic::parse) that text and compiling its patterns gives back
+    /// the same trie. This only holds for dictionaries written by hypher:
+    /// Parsing a dictionary from elsewhere may lose nonstandard entries and
+    /// `NEXTLEVEL` sections, so compiling and exporting it again doesn't give
+    /// back the original.
+    ///
+    /// # Example
+    /// ```
+    /// # use hypher::{Dic, Lang, Trie};
+    /// let trie = Trie::new(Lang::English.patterns());
+    /// let mut dic = trie.to_dic();
+    /// (dic.left_min, dic.right_min) = (Some(2), Some(3));
+    /// let text = dic.to_string();
+    /// assert!(text.starts_with("UTF-8\nLEFTHYPHENMIN 2\nRIGHTHYPHENMIN 3\n"));
+    /// assert_eq!(Trie::new(Dic::parse(&text).patterns), trie);
+    /// ```
+    pub fn to_dic(&self) -> Dic {
+        Dic {
+            charset: "UTF-8".into(),
+            patterns: self.patterns().collect(),
+            ..Dic::default()
+        }
+    }
+
+    /// The state at the root of the trie.
+    pub(crate) fn root(&self) -> State<'_> {
+        State::root(&self.data)
+    }
+}
+
+impl Debug for Trie {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Trie({} bytes)", self.data.len())
+    }
+}
+
+/// An iterator over the patterns stored in a trie.
+///
+/// This struct is created by [`Lang::patterns`](crate::Lang::patterns) and
+/// [`Trie::patterns`].
+#[derive(Clone)]
+pub struct Patterns<'a> {
+    /// The states on the path from the root to the current state, each with
+    /// the index of its next transition to follow.
+    stack: Vec<(State<'a>, usize)>,
+    /// The bytes along the path from the root to the current state.
+    path: Vec<u8>,
+}
+
+impl<'a> Patterns<'a> {
+    /// Walk the trie below the given root.
+    pub(crate) fn new(root: State<'a>) -> Self {
+        Self { stack: vec![(root, 0)], path: vec![] }
+    }
+
+    /// An iterator that yields nothing.
+    pub(crate) fn empty() -> Self {
+        Self { stack: vec![], path: vec![] }
+    }
+}
+
+impl Iterator for Patterns<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Walk the trie depth-first and yield a pattern whenever we enter a
+        // state with levels.
+        loop {
+            let (state, idx) = self.stack.last_mut()?;
+            if *idx == state.trans.len() {
+                self.stack.pop();
+                self.path.pop();
+                continue;
+            }
+
+            let next = state.target(*idx);
+            self.path.push(state.trans[*idx]);
+            *idx += 1;
+            self.stack.push((next, 0));
+
+            if next.has_levels {
+                let mut pattern = vec![];
+                let mut prev = 0;
+                for (offset, level) in next.levels() {
+                    pattern.extend_from_slice(&self.path[prev..offset]);
+                    pattern.push(b'0' + level);
+                    prev = offset;
+                }
+                pattern.extend_from_slice(&self.path[prev..]);
+                return Some(String::from_utf8(pattern).unwrap());
+            }
+        }
+    }
+}
+
+impl FusedIterator for Patterns<'_> {}
+
+impl Debug for Patterns<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.pad("Patterns(..)")
+    }
+}
+
+/// Compile patterns into the bytes of a trie.
+fn compile<I>(patterns: I) -> Result<Vec<u8>, TrieError>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    // Insert the patterns sorted by their letters. Patterns without letters
+    // never match.
+    let mut sorted = BTreeMap::new();
+    for pattern in patterns {
+        let pattern = pattern.as_ref();
+        let letters: Vec<u8> = pattern.bytes().filter(|b| !b.is_ascii_digit()).collect();
+        if !letters.is_empty() {
+            sorted.insert(letters, String::from(pattern));
+        }
+    }
+
+    let mut builder = TrieBuilder::new();
+    for pattern in sorted.values() {
+        check(pattern)?;
+        builder.insert(pattern);
+    }
+    builder.compress();
+    builder.encode().ok_or(TrieError::TooLarge)
+}
+
+/// Check that the levels of a pattern can be encoded.
+fn check(pattern: &str) -> Result<(), TrieError> {
+    let mut levels = 0;
+    let mut dist = 0;
+    for b in pattern.bytes() {
+        if !b.is_ascii_digit() {
+            dist += 1;
+        } else if dist > 24 {
+            return Err(TrieError::TooLongGap(pattern.into()));
+        } else {
+            levels += 1;
+            dist = 0;
+        }
+    }
+
+    if levels > 15 {
+        return Err(TrieError::TooManyLevels(pattern.into()));
+    }
+
+    Ok(())
+}
+
+/// Decompile untrusted trie data into its patterns, checking every access.
+///
+/// Returns `None` if the data is malformed or if walking it takes more steps
+/// than it has bytes times [`STEPS_PER_BYTE`], which also stops at cycles.
+fn decompile(data: &[u8]) -> Option<Vec<String>> {
+    let root = u32::from_be_bytes(data.get(..4)?.try_into().ok()?) as usize;
+    let mut nodes = BTreeMap::new();
+    let mut budget = STEPS_PER_BYTE * data.len();
+    let mut patterns = vec![];
+
+    // Walk the trie depth-first like `Patterns`.
+    let mut stack = vec![(root, 0)];
+    let mut path = vec![];
+    decode(data, root, &mut nodes)?;
+    while let Some((addr, idx)) = stack.last_mut() {
+        let node = &nodes[addr];
+        if *idx == node.trans.len() {
+            stack.pop();
+            path.pop();
+            continue;
+        }
+
+        // Letters that are digits would be read as levels.
+        let b = node.trans[*idx];
+        if b.is_ascii_digit() {
+            return None;
+        }
+
+        budget = budget.checked_sub(1)?;
+        let next = node.targets[*idx];
+        *idx += 1;
+        path.push(b);
+        stack.push((next, 0));
+
+        let next = decode(data, next, &mut nodes)?;
+        if let Some((offset, len)) = next.levels {
+            let mut pattern = vec![];
+            let mut prev = 0;
+            let mut split = 0;
+            for &packed in data.get(offset..offset + len)? {
+                let dist = usize::from(packed / 10);
+                split += dist;
+                if dist > 24 || split > path.len() {
+                    return None;
+                }
+                pattern.extend_from_slice(&path[prev..split]);
+                pattern.push(b'0' + packed % 10);
+                prev = split;
+            }
+            pattern.extend_from_slice(&path[prev..]);
+            patterns.push(String::from_utf8(pattern).ok()?);
+        }
+    }
+
+    Some(patterns)
+}
+
+/// How many steps decompiling untrusted trie data may take per byte.
+///
+/// The bundled tries take less than twenty.
+const STEPS_PER_BYTE: usize = 64;
+
+/// Decode the node at the given address of untrusted trie data, checking
+/// every access, and cache it.
+fn decode<'a>(
+    data: &[u8],
+    addr: usize,
+    nodes: &'a mut BTreeMap<usize, Node>,
+) -> Option<&'a Node> {
+    let entry = match nodes.entry(addr) {
+        Entry::Occupied(entry) => return Some(entry.into_mut()),
+        Entry::Vacant(entry) => entry,
+    };
+
+    let node = data.get(addr..)?;
+    let header = *node.first()?;
+    let has_levels = header >> 7 != 0;
+    let stride = usize::from((header >> 5) & 3);
+    let mut count = usize::from(header & 31);
+    let mut pos = 1;
+
+    if count == 31 {
+        count = usize::from(*node.get(pos)?);
+        pos += 1;
+    }
+
+    let mut levels = None;
+    if has_levels {
+        let bytes = node.get(pos..pos + 2)?;
+        let offset = usize::from(bytes[0]) << 4 | usize::from(bytes[1]) >> 4;
+        levels = Some((offset, usize::from(bytes[1] & 15)));
+        pos += 2;
+    }
+
+    let trans = node.get(pos..pos + count)?.to_vec();
+    pos += count;
+
+    if count > 0 && stride == 0 {
+        return None;
+    }
+
+    let mut targets = vec![];
+    for chunk in node.get(pos..pos + stride * count)?.chunks(stride.max(1)) {
+        let delta = from_be_bytes(chunk);
+        targets.push(usize::try_from(addr as isize + delta).ok()?);
+    }
+
+    Some(entry.insert(Node { trans, targets, levels }))
+}
+
+/// Builds a trie from patterns.
+struct TrieBuilder {
+    root: usize,
+    nodes: Vec<Node>,
+    levels: Vec<(usize, u8)>,
+}
+
+/// A node in the trie.
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Node {
+    trans: Vec<u8>,
+    targets: Vec<usize>,
+    levels: Option<(usize, usize)>,
+}
+
+impl TrieBuilder {
+    /// Create a new trie with just the root node.
+    fn new() -> Self {
+        Self {
+            root: 0,
+            nodes: vec![Node::default()],
+            levels: vec![],
+        }
+    }
+
+    /// Insert a pattern like `.a1bc2d` into the trie.
+    fn insert(&mut self, pattern: &str) {
+        let mut state = 0;
+        let mut dist = 0;
+        let mut levels = vec![];
+
+        // Follow the existing transitions / add new ones.
+        for b in pattern.bytes() {
+            if b.is_ascii_digit() {
+                levels.push((dist, b - b'0'));
+                dist = 0;
+            } else {
+                let len = self.nodes.len();
+                let node = &mut self.nodes[state];
+                if let Some(i) = node.trans.iter().position(|&x| x == b) {
+                    state = node.targets[i];
+                } else {
+                    node.trans.push(b);
+                    node.targets.push(len);
+                    state = len;
+                    self.nodes.push(Node::default());
+                }
+                dist += 1;
+            }
+        }
+
+        // Try to reuse existing levels.
+        let mut offset = 0;
+        while offset < self.levels.len() && !self.levels[offset..].starts_with(&levels) {
+            offset += 1;
+        }
+
+        // If there was no matching level "substring", we must append the new
+        // levels at the end.
+        if offset == self.levels.len() {
+            self.levels.extend(&levels);
+        }
+
+        // Add levels for the final node.
+        self.nodes[state].levels = Some((offset, levels.len()));
+    }
+
+    /// Perform suffix compression on the trie.
+    fn compress(&mut self) {
+        let mut map = BTreeMap::new();
+        let mut new = vec![];
+        self.root = self.compress_node(0, &mut map, &mut new);
+        self.nodes = new;
+    }
+
+    /// Recursively compress a node.
+    fn compress_node(
+        &self,
+        node: usize,
+        map: &mut BTreeMap<Node, usize>,
+        new: &mut Vec<Node>,
+    ) -> usize {
+        let mut x = self.nodes[node].clone();
+        for target in x.targets.iter_mut() {
+            *target = self.compress_node(*target, map, new);
+        }
+        *map.entry(x.clone()).or_insert_with(|| {
+            let idx = new.len();
+            new.push(x);
+            idx
+        })
+    }
+
+    /// Encode the tree.
+    ///
+    /// Returns `None` if the tree is too large to encode.
+    fn encode(&self) -> Option<Vec<u8>> {
+        let start = 4 + self.levels.len();
+
+        // Compute an address estimate for each node. We can't know the final
+        // addresses yet because the addresses depend on the stride of each
+        // target list and that stride of the target lists depends on the
+        // addresses.
+        let mut addr = start;
+        let mut estimates = vec![];
+        for node in &self.nodes {
+            estimates.push(addr);
+            addr += 1
+                + ((node.trans.len() >= 31) as usize)
+                + 2 * (node.levels.is_some() as usize)
+                + (1 + 3) * node.trans.len();
+        }
+
+        // Use the address estimates to determine how many bytes to use for each
+        // state and compute the final addresses.
+        let mut addr = start;
+        let mut addrs = vec![];
+        let mut strides = vec![];
+        for (i, node) in self.nodes.iter().enumerate() {
+            let mut stride = 1;
+            for &t in &node.targets {
+                let delta = estimates[t] as isize - estimates[i] as isize;
+                stride = stride.max(how_many_bytes(delta)?);
+            }
+
+            addrs.push(addr);
+            strides.push(stride);
+            addr += 1
+                + ((node.trans.len() >= 31) as usize)
+                + 2 * (node.levels.is_some() as usize)
+                + (1 + stride) * node.trans.len();
+        }
+
+        let mut data = vec![];
+
+        // Encode the root address.
+        data.extend(u32::try_from(addrs[self.root]).ok()?.to_be_bytes());
+
+        // Encode the levels.
+        for &(dist, level) in &self.levels {
+            assert!(dist <= 24, "too high level distance");
+            assert!(level < 10, "too high level");
+            data.push(dist as u8 * 10 + level);
+        }
+
+        // Encode the nodes.
+        for ((node, &addr), stride) in self.nodes.iter().zip(&addrs).zip(strides) {
+            data.push(
+                (node.levels.is_some() as u8) << 7
+                    | (stride as u8) << 5
+                    | (node.trans.len().min(31) as u8),
+            );
+
+            if node.trans.len() >= 31 {
+                data.push(u8::try_from(node.trans.len()).expect("too many transitions"));
+            }
+
+            if let Some((offset, len)) = node.levels {
+                let offset = 4 + offset;
+                if offset >= 4096 {
+                    return None;
+                }
+                assert!(len < 16, "too high level count");
+
+                let offset_hi = (offset >> 4) as u8;
+                let offset_lo = ((offset & 15) << 4) as u8;
+                let len = len as u8;
+
+                data.push(offset_hi);
+                data.push(offset_lo | len);
+            }
+
+            data.extend(&node.trans);
+
+            for &target in &node.targets {
+                let delta = addrs[target] as isize - addr as isize;
+                to_be_bytes(&mut data, delta, stride)?;
+            }
+        }
+
+        Some(data)
+    }
+}
+
+/// How many bytes are needed to encode a signed number.
+///
+/// Returns `None` if it needs more than three.
+fn how_many_bytes(num: isize) -> Option<usize> {
+    if i8::try_from(num).is_ok() {
+        Some(1)
+    } else if i16::try_from(num).is_ok() {
+        Some(2)
+    } else if (-(1 << 23)..(1 << 23)).contains(&num) {
+        Some(3)
+    } else {
+        None
+    }
+}
+
+/// Encode a signed number with 1, 2 or 3 bytes.
+///
+/// Returns `None` if the number doesn't fit.
+fn to_be_bytes(buf: &mut Vec<u8>, num: isize, stride: usize) -> Option<()> {
+    if stride == 1 {
+        buf.extend(i8::try_from(num).ok()?.to_be_bytes());
+    } else if stride == 2 {
+        buf.extend(i16::try_from(num).ok()?.to_be_bytes());
+    } else if stride == 3 && how_many_bytes(num)? <= 3 {
+        let unsigned = (num + (1 << 23)) as usize;
+        buf.push((unsigned >> 16) as u8);
+        buf.push((unsigned >> 8) as u8);
+        buf.push(unsigned as u8);
+    } else {
+        return None;
+    }
+    Some(())
+}
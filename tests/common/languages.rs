@@ -0,0 +1,168 @@
+//! The supported languages and their pattern files.
+
+use std::fs;
+use std::path::Path;
+
+use hypher::{parse_tex, Dic};
+
+/// A language with its name, tag, aliases, script, pattern file and default
+/// bounds.
+pub type Language = (
+    &'static str,
+    &'static str,
+    &'static [&'static str],
+    &'static str,
+    &'static str,
+    Option<(u8, u8)>,
+);
+
+/// The supported languages.
+///
+/// The bounds are only given for languages whose pattern file doesn't state
+/// them as its `hyphenmins`.
+#[rustfmt::skip]
+pub const LANGUAGES: [Language; 77] = [
+    ("Afrikaans", "af", &[], "Latn", "hyph-af.tex", None),
+    ("Assamese", "as", &[], "Beng", "hyph-as.tex", None),
+    ("Belarusian", "be", &[], "Cyrl", "hyph-be.tex", None),
+    ("Bengali", "bn", &[], "Beng", "hyph-bn.tex", None),
+    ("Bulgarian", "bg", &[], "Cyrl", "hyph-bg.tex", None),
+    // The file has no header, but its notice says that both bounds should be
+    // set to 2, as lower values give incorrect breaks at the start of words.
+    ("Catalan", "ca", &[], "Latn", "hyph-ca.tex", Some((2, 2))),
+    // Sojka's patterns have no header. hyph-utf8 states 2 and 3 for its own
+    // Czech patterns, but hypher has always shipped these with 2 and 2, so
+    // they are kept to not change existing breaks.
+    ("Czech", "cs", &[], "Latn", "hyph-cs-sojka.tex", Some((2, 2))),
+    ("Danish", "da", &[], "Latn", "hyph-da.tex", None),
+    ("Galician", "gl", &[], "Latn", "hyph-gl.tex", None),
+    ("German", "de", &[], "Latn", "hyph-de-1996.tex", None),
+    ("Greek", "el", &[], "Grek", "hyph-el-monoton.tex", None),
+    ("English", "en", &[], "Latn", "hyph-en-us.tex", None),
+    ("Spanish", "es", &[], "Latn", "hyph-es.tex", None),
+    ("Estonian", "et", &[], "Latn", "hyph-et.tex", None),
+    ("Finnish", "fi", &[], "Latn", "hyph-fi.tex", None),
+    ("French", "fr", &[], "Latn", "hyph-fr.tex", None),
+    ("Gujarati", "gu", &[], "Gujr", "hyph-gu.tex", None),
+    ("Hindi", "hi", &[], "Deva", "hyph-hi.tex", None),
+    ("Croatian", "hr", &[], "Latn", "hyph-hr.tex", None),
+    ("Hungarian", "hu", &[], "Latn", "hyph-hu.tex", None),
+    ("Icelandic", "is", &[], "Latn", "hyph-is.tex", None),
+    ("Italian", "it", &[], "Latn", "hyph-it.tex", None),
+    ("Kannada", "kn", &[], "Knda", "hyph-kn.tex", None),
+    ("Georgian", "ka", &[], "Geor", "hyph-ka.tex", None),
+    ("Kurmanji", "ku", &[], "Latn", "hyph-kmr.tex", None),
+    ("Latin", "la", &[], "Latn", "hyph-la.tex", None),
+    ("LatinClassic", "la-x-classic", &[], "Latn", "hyph-la-x-classic.pat.txt", Some((2, 2))),
+    ("LatinLiturgical", "la-x-liturgic", &[], "Latn", "hyph-la-x-liturgic.pat.txt", Some((2, 2))),
+    ("Lithuanian", "lt", &[], "Latn", "hyph-lt.tex", None),
+    ("Malayalam", "ml", &[], "Mlym", "hyph-ml.tex", None),
+    ("Marathi", "mr", &[], "Deva", "hyph-mr.tex", None),
+    ("Mongolian", "mn", &[], "Cyrl", "hyph-mn.tex", None),
+    ("Dutch", "nl", &[], "Latn", "hyph-nl.tex", None),
+    ("Norwegian", "no", &[], "Latn", "hyph-no.tex", None),
+    ("NorwegianBokmal", "nb", &[], "Latn", "hyph-no.tex", None),
+    ("NorwegianNynorsk", "nn", &[], "Latn", "hyph-no.tex", None),
+    ("Oriya", "or", &[], "Orya", "hyph-or.tex", None),
+    ("Panjabi", "pa", &[], "Guru", "hyph-pa.tex", None),
+    ("Polish", "pl", &[], "Latn", "hyph-pl.tex", None),
+    ("Portuguese", "pt", &[], "Latn", "hyph-pt.tex", None),
+    ("Russian", "ru", &[], "Cyrl", "hyph-ru.tex", None),
+    ("Sanskrit", "sa", &[], "Deva", "hyph-sa.tex", None),
+    ("Serbian", "sr", &[], "Cyrl", "hyph-sh-cyrl.tex", None),
+    ("SerbianLatin", "sr-Latn", &[], "Latn", "hyph-sh-cyrl.tex", None),
+    ("Slovak", "sk", &[], "Latn", "hyph-sk.tex", None),
+    ("Slovenian", "sl", &[], "Latn", "hyph-sl.tex", None),
+    ("Albanian", "sq", &[], "Latn", "hyph-sq.tex", None),
+    ("Swedish", "sv", &[], "Latn", "hyph-sv.tex", None),
+    ("Tamil", "ta", &[], "Taml", "hyph-ta.tex", None),
+    ("Telugu", "te", &[], "Telu", "hyph-te.tex", None),
+    ("Turkmen", "tk", &[], "Latn", "hyph-tk.tex", None),
+    ("Turkish", "tr", &[], "Latn", "hyph-tr.tex", None),
+    ("Ukrainian", "uk", &[], "Cyrl", "hyph-uk.tex", None),
+    ("Basque", "eu", &[], "Latn", "hyph-eu.pat.txt", Some((2, 2))),
+    ("Welsh", "cy", &[], "Latn", "hyph-cy.pat.txt", Some((2, 3))),
+    ("Irish", "ga", &[], "Latn", "hyph-ga.pat.txt", Some((2, 3))),
+    ("Romansh", "rm", &[], "Latn", "hyph-rm.pat.txt", Some((2, 2))),
+    ("Occitan", "oc", &[], "Latn", "hyph-oc.pat.txt", Some((2, 2))),
+    ("Friulian", "fur", &[], "Latn", "hyph-fur.pat.txt", Some((2, 2))),
+    ("Piedmontese", "pms", &[], "Latn", "hyph-pms.pat.txt", Some((2, 2))),
+    ("Interlingua", "ia", &[], "Latn", "hyph-ia.pat.txt", Some((2, 2))),
+    ("Esperanto", "eo", &[], "Latn", "hyph-eo.pat.txt", Some((2, 2))),
+    ("Romanian", "ro", &[], "Latn", "hyph-ro.pat.txt", Some((2, 2))),
+    ("Latvian", "lv", &[], "Latn", "hyph-lv.pat.txt", Some((2, 2))),
+    ("UpperSorbian", "hsb", &[], "Latn", "hyph-hsb.pat.txt", Some((2, 2))),
+    ("ChurchSlavonic", "cu", &[], "Cyrs", "hyph-cu.pat.txt", Some((1, 2))),
+    ("Armenian", "hy", &[], "Armn", "hyph-hy.pat.txt", Some((1, 2))),
+    ("Ethiopic", "mul-Ethi", &["am", "ti"], "Ethi", "", Some((1, 1))),
+    ("Coptic", "cop", &[], "Copt", "hyph-cop.pat.txt", Some((1, 1))),
+    ("Pali", "pi", &[], "Latn", "hyph-pi.pat.txt", Some((1, 2))),
+    ("Indonesian", "id", &["ms"], "Latn", "hyph-id.pat.txt", Some((2, 2))),
+    ("Thai", "th", &[], "Thai", "dict-th.txt", Some((1, 1))),
+    ("Lao", "lo", &[], "Laoo", "dict-lo.txt", Some((1, 1))),
+    ("Khmer", "km", &[], "Khmr", "dict-km.txt", Some((1, 1))),
+    ("Korean", "ko", &[], "Kore", "", Some((1, 1))),
+    ("Cherokee", "chr", &[], "Cher", "", Some((1, 1))),
+    ("CanadianSyllabics", "mul-Cans", &["iu", "cr", "oj"], "Cans", "", Some((1, 1))),
+];
+
+/// Load the patterns of a language from its file, calling `f` with each one.
+pub fn load<F>(tag: &str, filename: &str, mut f: F)
+where
+    F: FnMut(&str),
+{
+    let path = Path::new("patterns").join(filename);
+    let text = fs::read_to_string(&path).unwrap();
+    let mut insert = |pat: &str| {
+        f(pat);
+
+        // Church Slavonic writes a word-initial `оу` with a narrow o in
+        // lowercase, but capitalized words lowercase to a normal o.
+        if tag == "cu" {
+            if let Some(rewritten) = initial_uk(pat) {
+                f(&rewritten);
+            }
+        }
+    };
+
+    if is_dictionary(filename) {
+        text.lines()
+            .filter(|line| !line.is_empty() && !line.starts_with('%'))
+            .for_each(|word| insert(&format!("1{word}")));
+    } else if filename.ends_with(".pat.txt") {
+        text.split_whitespace().for_each(insert);
+    } else if filename.ends_with(".dic") {
+        Dic::parse(&text).patterns.iter().for_each(|pat| insert(pat));
+    } else {
+        parse_tex(&text).into_iter().for_each(insert);
+    }
+}
+
+/// Rewrite the word-initial `ᲂу` digraphs of a Church Slavonic pattern
+/// with a normal o, as capitalized words are lowercased to.
+///
+/// A digraph is word-initial if it follows the word start or carries a
+/// breathing mark, which only word-initial vowels have. Others are kept, so
+/// that the rewritten pattern doesn't match an ordinary о followed by у in
+/// the middle of a word. Returns `None` if there is nothing to rewrite.
+fn initial_uk(pat: &str) -> Option<String> {
+    let mut rewritten = String::new();
+    let mut last = 0;
+    for (i, digraph) in pat.match_indices("ᲂу") {
+        let end = i + digraph.len();
+        let after_start =
+            pat[..i].trim_end_matches(|c: char| c.is_ascii_digit()).ends_with('.');
+        let breathing = pat[end..].starts_with(['\u{485}', '\u{486}']);
+        if after_start || breathing {
+            rewritten.push_str(&pat[last..i]);
+            rewritten.push_str("оу");
+            last = end;
+        }
+    }
+    (last > 0).then(|| rewritten + &pat[last..])
+}
+
+/// Whether a file holds a word list instead of hyphenation patterns.
+pub fn is_dictionary(filename: &str) -> bool {
+    filename.starts_with("dict-")
+}
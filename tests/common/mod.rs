@@ -0,0 +1,27 @@
+//! Helpers shared by the integration tests.
+
+// Not every test uses every helper.
+#![allow(dead_code)]
+
+pub mod languages;
+
+/// A small xorshift generator, so that the random words are reproducible.
+pub struct Rng(u64);
+
+impl Rng {
+    /// A generator seeded from a name, like that of a language.
+    pub fn seeded(name: &str) -> Self {
+        Self(
+            name.bytes()
+                .fold(0x9e37_79b9_7f4a_7c15_u64, |h, b| h.wrapping_mul(31) ^ b as u64),
+        )
+    }
+
+    /// A random number below `n`.
+    pub fn below(&mut self, n: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % n as u64) as usize
+    }
+}
@@ -1,81 +1,49 @@
-#![allow(clippy::type_complexity)]
-
 use std::collections::HashMap;
 use std::fmt::{self, Write};
 use std::fs;
 use std::path::Path;
 
+use hypher::Trie;
+
+use self::common::languages::{is_dictionary, load, Language, LANGUAGES};
+
+mod common;
+
 #[test]
 fn generate_code() {
-    let mut languages: [(&str, &str, &[&str], &str, &str, u8, u8); 48] = [
-        ("Afrikaans", "af", &[], "Latn", "hyph-af.tex", 1, 2),
-        ("Assamese", "as", &[], "Beng", "hyph-as.tex", 2, 2),
-        ("Belarusian", "be", &[], "Cyrl", "hyph-be.tex", 2, 2),
-        ("Bengali", "bn", &[], "Beng", "hyph-bn.tex", 2, 2),
-        ("Bulgarian", "bg", &[], "Cyrl", "hyph-bg.tex", 2, 2),
-        ("Catalan", "ca", &[], "Latn", "hyph-ca.tex", 2, 2),
-        ("Czech", "cs", &[], "Latn", "hyph-cs-sojka.tex", 2, 2),
-        ("Danish", "da", &[], "Latn", "hyph-da.tex", 2, 2),
-        ("Galician", "gl", &[], "Latn", "hyph-gl.tex", 2, 2),
-        ("German", "de", &[], "Latn", "hyph-de-1996.tex", 2, 2),
-        ("Greek", "el", &[], "Grek", "hyph-el-monoton.tex", 1, 1),
-        ("English", "en", &[], "Latn", "hyph-en-us.tex", 2, 3),
-        ("Spanish", "es", &[], "Latn", "hyph-es.tex", 2, 2),
-        ("Estonian", "et", &[], "Latn", "hyph-et.tex", 2, 3),
-        ("Finnish", "fi", &[], "Latn", "hyph-fi.tex", 2, 2),
-        ("French", "fr", &[], "Latn", "hyph-fr.tex", 2, 2),
-        ("Gujarati", "gu", &[], "Gujr", "hyph-gu.tex", 2, 2),
-        ("Hindi", "hi", &[], "Deva", "hyph-hi.tex", 2, 2),
-        ("Croatian", "hr", &[], "Latn", "hyph-hr.tex", 2, 2),
-        ("Hungarian", "hu", &[], "Latn", "hyph-hu.tex", 2, 2),
-        ("Icelandic", "is", &[], "Latn", "hyph-is.tex", 2, 2),
-        ("Italian", "it", &[], "Latn", "hyph-it.tex", 2, 2),
-        ("Kannada", "kn", &[], "Knda", "hyph-kn.tex", 2, 2),
-        ("Georgian", "ka", &[], "Geor", "hyph-ka.tex", 1, 2),
-        ("Kurmanji", "ku", &[], "Latn", "hyph-kmr.tex", 2, 2),
-        ("Latin", "la", &[], "Latn", "hyph-la.tex", 2, 2),
-        ("Lithuanian", "lt", &[], "Latn", "hyph-lt.tex", 2, 2),
-        ("Malayalam", "ml", &[], "Mlym", "hyph-ml.tex", 2, 2),
-        ("Marathi", "mr", &[], "Deva", "hyph-mr.tex", 2, 2),
-        ("Mongolian", "mn", &[], "Cyrl", "hyph-mn.tex", 2, 2),
-        ("Dutch", "nl", &[], "Latn", "hyph-nl.tex", 2, 2),
-        ("Norwegian", "no", &["nb", "nn"], "Latn", "hyph-no.tex", 2, 2),
-        ("Oriya", "or", &[], "Orya", "hyph-or.tex", 2, 2),
-        ("Panjabi", "pa", &[], "Guru", "hyph-pa.tex", 2, 2),
-        ("Polish", "pl", &[], "Latn", "hyph-pl.tex", 2, 2),
-        ("Portuguese", "pt", &[], "Latn", "hyph-pt.tex", 2, 3),
-        ("Russian", "ru", &[], "Cyrl", "hyph-ru.tex", 2, 2),
-        ("Sanskrit", "sa", &[], "Deva", "hyph-sa.tex", 2, 2),
-        ("Serbian", "sr", &[], "Cyrl", "hyph-sh-cyrl.tex", 2, 2),
-        ("Slovak", "sk", &[], "Latn", "hyph-sk.tex", 2, 3),
-        ("Slovenian", "sl", &[], "Latn", "hyph-sl.tex", 2, 2),
-        ("Albanian", "sq", &[], "Latn", "hyph-sq.tex", 2, 2),
-        ("Swedish", "sv", &[], "Latn", "hyph-sv.tex", 2, 2),
-        ("Tamil", "ta", &[], "Taml", "hyph-ta.tex", 2, 2),
-        ("Telugu", "te", &[], "Telu", "hyph-te.tex", 2, 2),
-        ("Turkmen", "tk", &[], "Latn", "hyph-tk.tex", 2, 2),
-        ("Turkish", "tr", &[], "Latn", "hyph-tr.tex", 2, 2),
-        ("Ukrainian", "uk", &[], "Cyrl", "hyph-uk.tex", 2, 2),
-    ];
-
+    let mut languages = LANGUAGES;
     languages.sort();
 
-    // Build the tries.
+    // Build the tries. Languages with the same pattern file share a trie,
+    // which is named after the first of them. Word lists for languages that
+    // are segmented through a dictionary are built into the same kind of
+    // trie, with a level at the start of each word marking where it ends.
+    // Languages in syllabic scripts have no file and need no trie.
     let mut fresh = true;
-    for (_, iso, _, _, filename, ..) in languages {
-        let path = Path::new("patterns").join(filename);
-        let tex = fs::read_to_string(&path).unwrap();
-        let mut builder = TrieBuilder::new();
-        parse(&tex, |pat| builder.insert(pat));
-        builder.compress();
-        let trie = builder.encode();
-        let path = format!("tries/{iso}.bin");
+    let mut tries = HashMap::new();
+    for (_, tag, _, _, filename, ..) in languages {
+        if filename.is_empty() || tries.contains_key(filename) {
+            continue;
+        }
+
+        let mut patterns = vec![];
+        load(tag, filename, |pat| patterns.push(pat.to_string()));
+        let trie = Trie::new(patterns).as_bytes().to_vec();
+        let name = tag.to_lowercase();
+        let path = format!("tries/{name}.bin");
         fresh &= write_check(&path, trie);
+        tries.insert(filename, name);
     }
 
+    // Read the headers of the pattern files.
+    let headers: HashMap<_, _> = languages
+        .iter()
+        .filter_map(|&(.., filename, _)| Some((filename, header(filename)?)))
+        .collect();
+
     // Build the lang.rs file.
     let mut text = String::new();
-    write_lang(&mut text, &languages).unwrap();
+    write_lang(&mut text, &languages, &tries, &headers).unwrap();
     fresh &= write_check("src/lang.rs", text.into_bytes());
 
     if !fresh {
@@ -91,7 +59,9 @@ fn write_check(path: &str, data: Vec<u8>) -> bool {
 
 fn write_lang(
     w: &mut String,
-    languages: &[(&str, &str, &[&str], &str, &str, u8, u8)],
+    languages: &[Language],
+    tries: &HashMap<&str, String>,
+    headers: &HashMap<&str, Yaml>,
 ) -> fmt::Result {
     writeln!(w, "// This file is generated by tests/generate.rs")?;
     writeln!(w, "// Do not edit by hand!")?;
@@ -99,20 +69,36 @@ fn write_lang(
 
     writeln!(w, "/// A language you can hyphenate in.")?;
     writeln!(w, "///")?;
-    writeln!(w, "/// Lists for each language also the ISO 639-1 two")?;
-    writeln!(w, "/// letter language code and the ISO 15924 four letter")?;
-    writeln!(w, "/// script code.")?;
+    writeln!(w, "/// Lists for each language also the ISO 639 language")?;
+    writeln!(w, "/// code and the ISO 15924 four letter script code.")?;
+    writeln!(w, "/// Languages without a two letter ISO 639-1 code or")?;
+    writeln!(w, "/// variants of another language can't be selected")?;
+    writeln!(w, "/// through [`Lang::from_iso`].")?;
     writeln!(w, "#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]")?;
     writeln!(w, "#[non_exhaustive]")?;
     writeln!(w, "pub enum Lang {{")?;
 
-    for &(name, iso, isos, script, ..) in languages {
-        let feature = name.to_lowercase();
-        write!(w, "    /// Hyphenation for _{name}._ (Code: `{iso}`, ")?;
+    for &(name, tag, isos, script, filename, ..) in languages {
+        let feature = feature(name);
+        write!(w, "    /// Hyphenation for _{}._ (Code: `{tag}`, ", display(name))?;
         for code in isos {
             write!(w, "Alias: `{code}`, ")?;
         }
         writeln!(w, "Script, `{script}`, Feature: `{feature}`)")?;
+
+        // Languages in the same script with the same patterns hyphenate alike.
+        let others: Vec<_> = languages
+            .iter()
+            .filter(|other| other.0 != name && other.3 == script)
+            .filter(|other| !filename.is_empty() && other.4 == filename)
+            .map(|other| display(other.0))
+            .collect();
+        if !others.is_empty() {
+            let stem = filename.split('.').next().unwrap();
+            writeln!(w, "    ///")?;
+            writeln!(w, "    /// Uses the shared patterns of `{stem}`, so it")?;
+            writeln!(w, "    /// hyphenates like {}.", others.join(" and "))?;
+        }
         write!(w, "    ")?;
         write_cfg(w, &feature)?;
         writeln!(w, "    {name},")?;
@@ -123,16 +109,29 @@ fn write_lang(
 
     writeln!(w, "impl Lang {{")?;
 
-    // Implementation of `from_iso`.
+    // The list of all languages.
+    writeln!(w, "    /// All languages enabled through features.")?;
+    writeln!(w, "    pub const ALL: &'static [Self] = &[")?;
+    for (name, ..) in languages {
+        write!(w, "        ")?;
+        write_cfg(w, &feature(name))?;
+        writeln!(w, "        Self::{name},")?;
+    }
+    writeln!(w, "    ];")?;
+    writeln!(w)?;
+
+    // Implementation of `from_iso`. Languages with a longer tag are variants
+    // that can't be selected by ISO 639-1 code alone.
     writeln!(w, "    /// Select a language using its ISO 639-1 code.")?;
     writeln!(w, "    pub fn from_iso(code: [u8; 2]) -> Option<Self> {{")?;
     writeln!(w, "        match &code {{")?;
-    for &(name, iso, isos, ..) in languages {
-        let feature = name.to_lowercase();
-        write!(w, "            ")?;
-        write_cfg(w, &feature)?;
-        writeln!(w, r#"            b"{iso}" => Some(Self::{name}),"#)?;
-        for code in isos {
+    for &(name, tag, isos, ..) in languages {
+        let feature = feature(name);
+        let codes = (tag.len() == 2)
+            .then_some(tag)
+            .into_iter()
+            .chain(isos.iter().copied());
+        for code in codes {
             write!(w, "            ")?;
             write_cfg(w, &feature)?;
             writeln!(w, r#"            b"{code}" => Some(Self::{name}),"#)?;
@@ -143,305 +142,381 @@ fn write_lang(
     writeln!(w, "    }}")?;
     writeln!(w)?;
 
+    // Implementation of `from_iso_and_script`. Only languages whose tag
+    // carries a script subtag need their own arm.
+    writeln!(w, "    /// Select a language using its ISO 639-1 code and its")?;
+    writeln!(w, "    /// ISO 15924 script code (e.g. `Latn`).")?;
+    writeln!(w, "    ///")?;
+    writeln!(w, "    /// Falls back to [`from_iso`](Self::from_iso) if there")?;
+    writeln!(w, "    /// is no language specific to the script.")?;
+    writeln!(w, "    pub fn from_iso_and_script(")?;
+    writeln!(w, "        code: [u8; 2],")?;
+    writeln!(w, "        script: [u8; 4],")?;
+    writeln!(w, "    ) -> Option<Self> {{")?;
+    writeln!(w, "        let script = script.map(|b| b.to_ascii_lowercase());")?;
+    writeln!(w, "        match (&code, &script) {{")?;
+    for &(name, tag, _, script, ..) in languages {
+        if let Some((code, subtag)) = tag.split_once('-') {
+            if code.len() == 2 && subtag == script {
+                let script = script.to_lowercase();
+                write!(w, "            ")?;
+                write_cfg(w, &feature(name))?;
+                writeln!(
+                    w,
+                    r#"            (b"{code}", b"{script}") => Some(Self::{name}),"#
+                )?;
+            }
+        }
+    }
+    writeln!(w, "            _ => Self::from_iso(code),")?;
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w)?;
+
     // Implementation of `bounds`.
-    writeln!(w, "    /// The default number of chars to each side between")?;
-    writeln!(w, "    /// which breaking is forbidden.")?;
+    writeln!(w, "    /// The default number of grapheme clusters to each")?;
+    writeln!(w, "    /// side between which breaking is forbidden.")?;
     writeln!(w, "    ///")?;
-    writeln!(w, "    /// This follows typographic conventions.")?;
+    writeln!(w, "    /// These are the `hyphenmins` for typesetting stated")?;
+    writeln!(w, "    /// in the language's pattern file, if there are any.")?;
+    writeln!(w, "    /// Otherwise, they follow typographic conventions.")?;
     writeln!(w, "    pub fn bounds(self) -> (usize, usize) {{")?;
     writeln!(w, "        match self {{")?;
-    for (name, .., lmin, rmin) in languages {
-        let feature = name.to_lowercase();
+    for &(name, .., filename, bounds) in languages {
+        let (lmin, rmin) = match (headers.get(filename).and_then(hyphenmins), bounds) {
+            (Some(_), Some(_)) => panic!("{name} has bounds, but its file states them"),
+            (None, None) => panic!("{name} has no bounds"),
+            (Some(stated), None) => stated,
+            (None, Some(given)) => given,
+        };
         write!(w, "            ")?;
-        write_cfg(w, &feature)?;
+        write_cfg(w, &feature(name))?;
         writeln!(w, "            Self::{name} => ({lmin}, {rmin}),")?;
     }
     writeln!(w, "        }}")?;
     writeln!(w, "    }}")?;
     writeln!(w)?;
 
-    // Implementation of `root`.
-    writeln!(w, "    fn root(self) -> State<'static> {{")?;
+    // Implementation of `metadata`.
+    writeln!(w, "    /// Information about the language's patterns from")?;
+    writeln!(w, "    /// the header of their file.")?;
+    writeln!(w, "    ///")?;
+    writeln!(w, "    /// Returns `None` if the file has no such header.")?;
+    writeln!(w, "    pub fn metadata(self) -> Option<Metadata> {{")?;
     writeln!(w, "        match self {{")?;
-    for (name, iso, ..) in languages {
-        let feature = name.to_lowercase();
+    for &(name, .., filename, _) in languages {
         write!(w, "            ")?;
-        write_cfg(w, &feature)?;
-        write!(w, "            Self::{name} => State::root(")?;
-        writeln!(w, "include_bytes!(\"../tries/{iso}.bin\")),")?;
+        write_cfg(w, &feature(name))?;
+        write!(w, "            Self::{name} => ")?;
+        match headers.get(filename) {
+            Some(header) => write_metadata(w, header)?,
+            None => writeln!(w, "None,")?,
+        }
     }
     writeln!(w, "        }}")?;
     writeln!(w, "    }}")?;
-    writeln!(w, "}}")
-}
-
-fn write_cfg(w: &mut String, feature: &str) -> fmt::Result {
-    writeln!(w, r#"#[cfg(feature = "{feature}")]"#)
-}
-
-/// Parse a TeX pattern file, calling `f` with each pattern.
-pub fn parse<F>(tex: &str, mut f: F)
-where
-    F: FnMut(&str),
-{
-    let mut s = Scanner(tex);
-    while let Some(c) = s.eat() {
-        match c {
-            '%' => {
-                s.eat_while(|c| c != '\n');
-            }
-            '\\' if s.eat_if("patterns{") => loop {
-                let pat = s.eat_while(|c| c != '}' && c != '%' && !c.is_whitespace());
-                if !pat.is_empty() {
-                    f(pat);
-                }
-                match s.eat() {
-                    Some('}') => break,
-                    Some('%') => s.eat_while(|c| c != '\n'),
-                    _ => s.eat_while(char::is_whitespace),
-                };
-            },
-            _ => {}
-        }
-    }
-}
-
-struct Scanner<'a>(&'a str);
+    writeln!(w)?;
 
-impl<'a> Scanner<'a> {
-    fn eat(&mut self) -> Option<char> {
-        let mut chars = self.0.chars();
-        let c = chars.next();
-        self.0 = chars.as_str();
-        c
+    // Implementation of `hyphen`.
+    writeln!(w, "    /// The hyphen character to insert when breaking a")?;
+    writeln!(w, "    /// word in this language.")?;
+    writeln!(w, "    ///")?;
+    writeln!(w, "    /// This is `-` unless the language's script has a")?;
+    writeln!(w, "    /// dedicated hyphen, like `֊` for Armenian. Returns")?;
+    writeln!(w, "    /// `None` for languages that break without inserting")?;
+    writeln!(w, "    /// anything, like Thai or Korean.")?;
+    writeln!(w, "    pub fn hyphen(self) -> Option<char> {{")?;
+    writeln!(w, "        match self {{")?;
+    for (name, _, _, _, filename, ..) in languages {
+        let hyphen = hyphen(name, filename);
+        write!(w, "            ")?;
+        write_cfg(w, &feature(name))?;
+        writeln!(w, "            Self::{name} => {hyphen:?},")?;
     }
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w)?;
 
-    fn eat_if(&mut self, pat: &str) -> bool {
-        let matches = self.0.starts_with(pat);
-        if matches {
-            self.0 = &self.0[pat.len()..];
+    // Implementation of `engine`.
+    writeln!(w, "    fn engine(self) -> Engine {{")?;
+    writeln!(w, "        match self {{")?;
+    for (name, _, _, _, filename, ..) in languages {
+        write!(w, "            ")?;
+        write_cfg(w, &feature(name))?;
+        write!(w, "            Self::{name} => ")?;
+        if filename.is_empty() {
+            writeln!(w, "Engine::Syllabic,")?;
+            continue;
         }
-        matches
+
+        let trie = &tries[filename];
+        let engine = if is_dictionary(filename) { "Dictionary" } else { "Patterns" };
+        write!(w, "Engine::{engine}(State::root(")?;
+        writeln!(w, "include_bytes!(\"../tries/{trie}.bin\"))),")?;
     }
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")
+}
 
-    fn eat_while(&mut self, f: fn(char) -> bool) -> &'a str {
-        let mut offset = 0;
-        let mut chars = self.0.chars();
-        while chars.next().map_or(false, f) {
-            offset = self.0.len() - chars.as_str().len();
+/// Write the metadata of a language from the header of its pattern file.
+fn write_metadata(w: &mut String, header: &Yaml) -> fmt::Result {
+    let field = |key| header.get(key).and_then(Yaml::as_str);
+    let title = field("title").unwrap();
+    writeln!(w, "Some(Metadata {{")?;
+    writeln!(w, "                title: {title:?},")?;
+    writeln!(w, "                version: {:?},", field("version"))?;
+    writeln!(w, "                copyright: {:?},", field("copyright"))?;
+
+    // Authors are listed with their name and contact or just by name.
+    let authors: Vec<&str> = match header.get("authors") {
+        Some(Yaml::List(items)) => items
+            .iter()
+            .filter_map(|item| item.as_str().or_else(|| item.get("name")?.as_str()))
+            .collect(),
+        Some(author) => author.as_str().into_iter().collect(),
+        None => vec![],
+    };
+    writeln!(w, "                authors: &{authors:?},")?;
+
+    // A list of licences may start with a note that any of them applies.
+    let licences: Vec<&Yaml> = match header.get("licence") {
+        Some(Yaml::List(items)) => {
+            items.iter().filter(|item| matches!(item, Yaml::Map(_))).collect()
         }
-        let head = &self.0[..offset];
-        self.0 = &self.0[offset..];
-        head
+        Some(licence) => vec![licence],
+        None => vec![],
+    };
+    writeln!(w, "                licences: &[")?;
+    for licence in licences {
+        let field = |key| match licence {
+            Yaml::Str(text) if key == "text" => Some(text.as_str()),
+            _ => licence.get(key).and_then(Yaml::as_str),
+        };
+        writeln!(w, "                    Licence {{")?;
+        writeln!(w, "                        name: {:?},", field("name"))?;
+        writeln!(w, "                        version: {:?},", field("version"))?;
+        writeln!(
+            w,
+            "                        or_later: {},",
+            field("or_later") == Some("true")
+        )?;
+        writeln!(w, "                        url: {:?},", field("url"))?;
+        writeln!(w, "                        text: {:?},", field("text"))?;
+        writeln!(w, "                    }},")?;
     }
+    writeln!(w, "                ],")?;
+    writeln!(w, "            }}),")
 }
 
-/// Builds a trie from patterns.
-struct TrieBuilder {
-    root: usize,
-    nodes: Vec<Node>,
-    levels: Vec<(usize, u8)>,
+/// The `hyphenmins` for typesetting in the header of a pattern file, falling
+/// back to those for generating the patterns.
+fn hyphenmins(header: &Yaml) -> Option<(u8, u8)> {
+    let mins = header.get("hyphenmins")?;
+    let mins = mins.get("typesetting").or_else(|| mins.get("generation"))?;
+    let min = |key| mins.get(key)?.as_str()?.split(" #").next()?.trim().parse().ok();
+    Some((min("left")?, min("right")?))
 }
 
-/// A node in the trie.
-#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
-struct Node {
-    trans: Vec<u8>,
-    targets: Vec<usize>,
-    levels: Option<(usize, usize)>,
+/// A value in the YAML header of a pattern file.
+#[derive(Debug)]
+enum Yaml {
+    Str(String),
+    List(Vec<Yaml>),
+    Map(Vec<(String, Yaml)>),
 }
 
-impl TrieBuilder {
-    /// Create a new trie with just the root node.
-    fn new() -> Self {
-        Self {
-            root: 0,
-            nodes: vec![Node::default()],
-            levels: vec![],
+impl Yaml {
+    /// The value for a key, if this is a map.
+    fn get(&self, key: &str) -> Option<&Yaml> {
+        match self {
+            Self::Map(entries) => entries.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
         }
     }
 
-    /// Insert a pattern like `.a1bc2d` into the trie.
-    fn insert(&mut self, pattern: &str) {
-        let mut state = 0;
-        let mut dist = 0;
-        let mut levels = vec![];
-
-        // Follow the existing transitions / add new ones.
-        for b in pattern.bytes() {
-            if b.is_ascii_digit() {
-                levels.push((dist, b - b'0'));
-                dist = 0;
-            } else {
-                let len = self.nodes.len();
-                let node = &mut self.nodes[state];
-                if let Some(i) = node.trans.iter().position(|&x| x == b) {
-                    state = node.targets[i];
-                } else {
-                    node.trans.push(b);
-                    node.targets.push(len);
-                    state = len;
-                    self.nodes.push(Node::default());
-                }
-                dist += 1;
-            }
-        }
-
-        // Try to reuse existing levels.
-        let mut offset = 0;
-        while offset < self.levels.len() && !self.levels[offset..].starts_with(&levels) {
-            offset += 1;
-        }
-
-        // If there was no matching level "substring", we must append the new
-        // levels at the end.
-        if offset == self.levels.len() {
-            self.levels.extend(&levels);
+    /// The text, if this is a string.
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::Str(text) => Some(text),
+            _ => None,
         }
-
-        // Add levels for the final node.
-        self.nodes[state].levels = Some((offset, levels.len()));
     }
+}
 
-    /// Perform suffix compression on the trie.
-    fn compress(&mut self) {
-        let mut map = HashMap::new();
-        let mut new = vec![];
-        self.root = self.compress_node(0, &mut map, &mut new);
-        self.nodes = new;
+/// Parse the header of a hyph-utf8 pattern file, which is YAML in comments.
+///
+/// Returns `None` for files without such a header. Only the subset of YAML
+/// that the headers use is supported.
+fn header(filename: &str) -> Option<Yaml> {
+    if !filename.ends_with(".tex") {
+        return None;
     }
 
-    /// Recursively compress a node.
-    fn compress_node(
-        &self,
-        node: usize,
-        map: &mut HashMap<Node, usize>,
-        new: &mut Vec<Node>,
-    ) -> usize {
-        let mut x = self.nodes[node].clone();
-        for target in x.targets.iter_mut() {
-            *target = self.compress_node(*target, map, new);
+    // The header ends at a line of equals signs or the first line that is no
+    // comment.
+    let path = Path::new("patterns").join(filename);
+    let text = fs::read_to_string(path).unwrap();
+    let mut lines = vec![];
+    for line in text.lines() {
+        let line = match line.strip_prefix('%') {
+            Some(line) if !line.trim_start().starts_with("===") => line,
+            _ => break,
+        };
+        if !line.trim().is_empty() {
+            let content = line.trim_start();
+            lines.push((line.len() - content.len(), content.trim_end().to_string()));
         }
-        *map.entry(x.clone()).or_insert_with(|| {
-            let idx = new.len();
-            new.push(x);
-            idx
-        })
     }
 
-    /// Encode the tree.
-    fn encode(&self) -> Vec<u8> {
-        let start = 4 + self.levels.len();
-
-        // Compute an address estimate for each node. We can't know the final
-        // addresses yet because the addresses depend on the stride of each
-        // target list and that stride of the target lists depends on the
-        // addresses.
-        let mut addr = start;
-        let mut estimates = vec![];
-        for node in &self.nodes {
-            estimates.push(addr);
-            addr += 1
-                + ((node.trans.len() >= 31) as usize)
-                + 2 * (node.levels.is_some() as usize)
-                + (1 + 3) * node.trans.len();
-        }
-
-        // Use the address estimates to determine how many bytes to use for each
-        // state and compute the final addresses.
-        let mut addr = start;
-        let mut addrs = vec![];
-        let mut strides = vec![];
-        for (i, node) in self.nodes.iter().enumerate() {
-            let stride = node
-                .targets
-                .iter()
-                .map(|&t| how_many_bytes(estimates[t] as isize - estimates[i] as isize))
-                .max()
-                .unwrap_or(1);
-
-            addrs.push(addr);
-            strides.push(stride);
-            addr += 1
-                + ((node.trans.len() >= 31) as usize)
-                + 2 * (node.levels.is_some() as usize)
-                + (1 + stride) * node.trans.len();
-        }
-
-        let mut data = vec![];
+    // Some files have a note in front of the header.
+    let mut i = lines.iter().position(|(_, content)| key(content).is_some())?;
+    let header = parse_block(&mut lines, &mut i)?;
+    header.get("title")?;
+    Some(header)
+}
 
-        // Encode the root address.
-        data.extend(u32::try_from(addrs[self.root]).unwrap().to_be_bytes());
+/// Parse the lines from the given one on that are indented at least as much
+/// as it.
+fn parse_block(lines: &mut [(usize, String)], i: &mut usize) -> Option<Yaml> {
+    let (indent, content) = lines.get(*i)?;
+    if content.starts_with('-') {
+        parse_list(lines, i, *indent)
+    } else if key(content).is_some() {
+        parse_map(lines, i, *indent)
+    } else {
+        Some(Yaml::Str(parse_text(lines, i, *indent, ' ')))
+    }
+}
 
-        // Encode the levels.
-        for &(dist, level) in &self.levels {
-            assert!(dist <= 24, "too high level distance");
-            assert!(level < 10, "too high level");
-            data.push(dist as u8 * 10 + level);
+/// Parse a list whose items start with `-` at the given indent.
+fn parse_list(
+    lines: &mut [(usize, String)],
+    i: &mut usize,
+    indent: usize,
+) -> Option<Yaml> {
+    let mut items = vec![];
+    while let Some((_, content)) = lines.get(*i).filter(|(n, _)| *n == indent) {
+        let rest = match content.strip_prefix('-') {
+            Some(rest) => rest.trim_start(),
+            None => break,
+        };
+        if rest.is_empty() {
+            // The item is on the following lines.
+            *i += 1;
+            match lines.get(*i) {
+                Some((n, _)) if *n > indent => items.push(parse_block(lines, i)?),
+                _ => items.push(Yaml::Str(String::new())),
+            }
+        } else {
+            // The item starts on this line, so parse it as if the dash were
+            // indentation.
+            let inner = indent + content.len() - rest.len();
+            lines[*i] = (inner, rest.to_string());
+            items.push(parse_block(lines, i)?);
         }
+    }
+    Some(Yaml::List(items))
+}
 
-        // Encode the nodes.
-        for ((node, &addr), stride) in self.nodes.iter().zip(&addrs).zip(strides) {
-            data.push(
-                (node.levels.is_some() as u8) << 7
-                    | (stride as u8) << 5
-                    | (node.trans.len().min(31) as u8),
-            );
-
-            if node.trans.len() >= 31 {
-                data.push(u8::try_from(node.trans.len()).expect("too many transitions"));
+/// Parse a map whose keys are at the given indent.
+fn parse_map(
+    lines: &mut [(usize, String)],
+    i: &mut usize,
+    indent: usize,
+) -> Option<Yaml> {
+    let mut entries = vec![];
+    while let Some((_, content)) = lines.get(*i).filter(|(n, _)| *n == indent) {
+        let (k, rest) = match key(content) {
+            Some((k, rest)) => (k.to_string(), rest.to_string()),
+            None => break,
+        };
+
+        // Comments are only stripped after block indicators, as values may
+        // contain `#`.
+        let indicator = rest.split(" #").next().unwrap().trim();
+        let value = if indicator.starts_with(['>', '|']) {
+            *i += 1;
+            let sep = if indicator.starts_with('>') { ' ' } else { '\n' };
+            match lines.get(*i) {
+                Some((n, _)) if *n > indent => {
+                    Yaml::Str(parse_text(lines, i, indent + 1, sep))
+                }
+                _ => Yaml::Str(String::new()),
             }
-
-            if let Some((offset, len)) = node.levels {
-                let offset = 4 + offset;
-                assert!(offset < 4096, "too high level offset");
-                assert!(len < 16, "too high level count");
-
-                let offset_hi = (offset >> 4) as u8;
-                let offset_lo = ((offset & 15) << 4) as u8;
-                let len = len as u8;
-
-                data.push(offset_hi);
-                data.push(offset_lo | len);
+        } else if rest.is_empty() {
+            *i += 1;
+            match lines.get(*i) {
+                Some((n, _)) if *n > indent => parse_block(lines, i)?,
+                _ => Yaml::Str(String::new()),
             }
+        } else {
+            // A plain value may continue on more indented lines.
+            lines[*i].1 = rest;
+            Yaml::Str(parse_text(lines, i, indent + 1, ' '))
+        };
+        entries.push((k, value));
+    }
+    Some(Yaml::Map(entries))
+}
 
-            data.extend(&node.trans);
-
-            for &target in &node.targets {
-                let delta = addrs[target] as isize - addr as isize;
-                to_be_bytes(&mut data, delta, stride);
-            }
+/// Parse text from the given line on, continuing on the lines that are
+/// indented at least as much as `min`.
+fn parse_text(lines: &[(usize, String)], i: &mut usize, min: usize, sep: char) -> String {
+    let mut text = lines[*i].1.clone();
+    *i += 1;
+    while let Some((n, content)) = lines.get(*i) {
+        if *n < min {
+            break;
         }
-
-        data
+        text.push(sep);
+        text.push_str(content);
+        *i += 1;
     }
+    text.trim_matches('"').to_string()
 }
 
-/// How many bytes are needed to encode a signed number.
-fn how_many_bytes(num: isize) -> usize {
-    if i8::try_from(num).is_ok() {
-        1
-    } else if i16::try_from(num).is_ok() {
-        2
-    } else if (-(1 << 23)..(1 << 23)).contains(&num) {
-        3
-    } else {
-        panic!("too large number");
+/// Split a line like `key: value` into the key and the value.
+fn key(content: &str) -> Option<(&str, &str)> {
+    let (k, rest) = content.split_once(':')?;
+    let valid = !k.is_empty() && k.chars().all(|c| c.is_alphanumeric() || c == '_');
+    (valid && (rest.is_empty() || rest.starts_with(' '))).then(|| (k, rest.trim()))
+}
+
+/// The hyphen character of a language, if it uses one.
+fn hyphen(name: &str, filename: &str) -> Option<char> {
+    match name {
+        _ if is_dictionary(filename) => None,
+        "Armenian" => Some('\u{58A}'),
+        "Korean" => None,
+        _ => Some('-'),
     }
 }
 
-/// Encode a signed number with 1, 2 or 3 bytes.
-fn to_be_bytes(buf: &mut Vec<u8>, num: isize, stride: usize) {
-    if stride == 1 {
-        buf.extend(i8::try_from(num).unwrap().to_be_bytes());
-    } else if stride == 2 {
-        buf.extend(i16::try_from(num).unwrap().to_be_bytes());
-    } else if stride == 3 {
-        let unsigned = (num + (1 << 23)) as usize;
-        buf.push((unsigned >> 16) as u8);
-        buf.push((unsigned >> 8) as u8);
-        buf.push(unsigned as u8);
-    } else {
-        panic!("invalid stride");
+/// The name of the feature that enables a language.
+fn feature(name: &str) -> String {
+    words(name).join("-").to_lowercase()
+}
+
+/// The name of a language as it is displayed in the docs.
+fn display(name: &str) -> String {
+    words(name).join(" ").replace("Bokmal", "Bokmål")
+}
+
+/// Split a camel case language name into its words.
+fn words(name: &str) -> Vec<&str> {
+    let mut words = vec![];
+    let mut start = 0;
+    for (i, c) in name.char_indices().skip(1) {
+        if c.is_uppercase() {
+            words.push(&name[start..i]);
+            start = i;
+        }
     }
+    words.push(&name[start..]);
+    words
+}
+
+fn write_cfg(w: &mut String, feature: &str) -> fmt::Result {
+    writeln!(w, r#"#[cfg(feature = "{feature}")]"#)
 }
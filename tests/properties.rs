@@ -0,0 +1,134 @@
+use hypher::{grapheme_ends, hyphenate, hyphenate_bounded, Lang};
+
+use self::common::Rng;
+
+mod common;
+
+#[test]
+fn properties() {
+    for &lang in Lang::ALL {
+        let name = format!("{lang:?}");
+        let (alphabet, pieces) = alphabet(lang);
+        assert!(!alphabet.is_empty(), "no alphabet for {name}");
+
+        let mut rng = Rng::seeded(&name);
+        for _ in 0..300 {
+            let word = word(&mut rng, &alphabet, &pieces);
+            let (left_min, right_min) = lang.bounds();
+            check(lang, &word, left_min, right_min);
+
+            let left_min = rng.below(5);
+            let right_min = rng.below(5);
+            check(lang, &word, left_min, right_min);
+
+            // Uppercase some letters. Letters are only lowercased again for
+            // matching if that keeps their length, so only such ones change.
+            let changed: String = word
+                .chars()
+                .map(|c| {
+                    let mut upper = c.to_uppercase();
+                    match (upper.next(), upper.next()) {
+                        (Some(u), None)
+                            if rng.below(2) == 0
+                                && u.len_utf8() == c.len_utf8()
+                                && u.to_lowercase().eq([c]) =>
+                        {
+                            u
+                        }
+                        _ => c,
+                    }
+                })
+                .collect();
+            assert_eq!(
+                breaks(&changed, lang, left_min, right_min),
+                breaks(&word, lang, left_min, right_min),
+                "case change moved breaks of {word:?} in {name}",
+            );
+        }
+    }
+}
+
+/// Check the invariants of hyphenating a word.
+fn check(lang: Lang, word: &str, left_min: usize, right_min: usize) {
+    let context = || format!("{word:?} in {lang:?} with bounds {left_min}, {right_min}");
+
+    // The syllables are non-empty and reproduce the word.
+    let syllables: Vec<_> = hyphenate_bounded(word, lang, left_min, right_min).collect();
+    assert_eq!(syllables.concat(), word, "{}", context());
+    assert!(syllables.iter().all(|s| !s.is_empty()), "{}", context());
+
+    // Breaks fall between grapheme clusters and the bounds count them.
+    let ends: Vec<_> = grapheme_ends(word).collect();
+    let mut offset = 0;
+    for syllable in &syllables[..syllables.len().saturating_sub(1)] {
+        offset += syllable.len();
+        let before = ends.iter().position(|&end| end == offset);
+        let before =
+            before.unwrap_or_else(|| panic!("break in cluster: {}", context())) + 1;
+        assert!(before >= left_min.max(1), "{}", context());
+        assert!(ends.len() - before >= right_min.max(1), "{}", context());
+    }
+
+    // The size hint is exact at every step.
+    let mut iter = hyphenate_bounded(word, lang, left_min, right_min);
+    for remaining in (0..=syllables.len()).rev() {
+        assert_eq!(iter.size_hint(), (remaining, Some(remaining)), "{}", context());
+        iter.next();
+    }
+
+    if (left_min, right_min) == lang.bounds() {
+        assert_eq!(hyphenate(word, lang).collect::<Vec<_>>(), syllables);
+    }
+}
+
+/// The byte offsets of the breaks in a word.
+fn breaks(word: &str, lang: Lang, left_min: usize, right_min: usize) -> Vec<usize> {
+    let mut offsets = vec![];
+    let mut offset = 0;
+    for syllable in hyphenate_bounded(word, lang, left_min, right_min) {
+        offset += syllable.len();
+        offsets.push(offset);
+    }
+    offsets
+}
+
+/// The letters of a language's script and some longer pieces of words.
+///
+/// These are taken from the patterns or word list of the language. Languages
+/// in syllabic scripts have neither, so their script's block is used.
+fn alphabet(lang: Lang) -> (Vec<char>, Vec<String>) {
+    let pieces: Vec<String> = lang
+        .patterns()
+        .map(|pat| pat.replace(|c: char| c.is_ascii_digit() || c == '.', ""))
+        .filter(|letters| !letters.is_empty())
+        .collect();
+
+    let mut alphabet: Vec<char> = pieces.iter().flat_map(|p| p.chars()).collect();
+    if alphabet.is_empty() {
+        let block = match format!("{lang:?}").as_str() {
+            "Korean" => '\u{AC00}'..='\u{D7A3}',
+            "Ethiopic" => '\u{1200}'..='\u{137C}',
+            "Cherokee" => '\u{13A0}'..='\u{13F5}',
+            "CanadianSyllabics" => '\u{1401}'..='\u{167F}',
+            _ => return (vec![], vec![]),
+        };
+        alphabet.extend(block);
+    }
+
+    alphabet.sort();
+    alphabet.dedup();
+    (alphabet, pieces)
+}
+
+/// Generate a word from single letters and pieces of words.
+fn word(rng: &mut Rng, alphabet: &[char], pieces: &[String]) -> String {
+    let mut word = String::new();
+    for _ in 0..1 + rng.below(6) {
+        if !pieces.is_empty() && rng.below(3) == 0 {
+            word.push_str(&pieces[rng.below(pieces.len())]);
+        } else {
+            word.push(alphabet[rng.below(alphabet.len())]);
+        }
+    }
+    word
+}
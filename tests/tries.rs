@@ -0,0 +1,163 @@
+use std::collections::HashMap;
+use std::fs;
+
+use hypher::{parse_tex, Dic, Trie};
+
+use self::common::languages::{is_dictionary, load, LANGUAGES};
+use self::common::Rng;
+
+mod common;
+
+#[test]
+fn decompile_tries() {
+    for &lang in hypher::Lang::ALL {
+        let name = format!("{lang:?}");
+        let &(_, tag, _, _, filename, ..) =
+            LANGUAGES.iter().find(|row| row.0 == name).unwrap();
+
+        // A later pattern with the same letters replaces an earlier one.
+        let mut expected = HashMap::new();
+        if !filename.is_empty() {
+            load(tag, filename, |pat| {
+                let letters = pat.replace(|c: char| c.is_ascii_digit(), "");
+                expected.insert(letters, pat.to_string());
+            });
+        }
+
+        let mut expected: Vec<_> = expected.into_values().collect();
+        let mut decompiled: Vec<_> = lang.patterns().collect();
+        expected.sort();
+        decompiled.sort();
+        assert!(decompiled == expected, "decompiled patterns differ for {name}");
+    }
+}
+
+#[test]
+fn export_tries() {
+    let mut languages = LANGUAGES;
+    languages.sort();
+
+    for &lang in hypher::Lang::ALL {
+        let name = format!("{lang:?}");
+        let &(.., filename, _) = languages.iter().find(|row| row.0 == name).unwrap();
+        if filename.is_empty() {
+            continue;
+        }
+
+        // Find the language whose trie this one shares.
+        let &(_, tag, ..) = languages.iter().find(|row| row.4 == filename).unwrap();
+        let path = format!("tries/{}.bin", tag.to_lowercase());
+        let bin = fs::read(path).unwrap();
+
+        let trie = Trie::new(lang.patterns());
+        assert!(trie.as_bytes() == bin, "decompiled trie differs for {name}");
+        assert!(Trie::from_bytes(bin.clone()).is_some(), "can't load trie for {name}");
+
+        let tex = trie.to_tex();
+        let reimported = Trie::new(parse_tex(&tex));
+        assert!(reimported.as_bytes() == bin, "TeX export differs for {name}");
+
+        let dic = trie.to_dic().to_string();
+        let reimported = Trie::new(Dic::parse(&dic).patterns);
+        assert!(reimported.as_bytes() == bin, "libhyphen export differs for {name}");
+    }
+}
+
+#[test]
+fn differential() {
+    for &lang in hypher::Lang::ALL {
+        let name = format!("{lang:?}");
+        let &(_, tag, _, _, filename, ..) =
+            LANGUAGES.iter().find(|row| row.0 == name).unwrap();
+        if filename.is_empty() || is_dictionary(filename) {
+            continue;
+        }
+
+        let mut patterns = vec![];
+        load(tag, filename, |pat| patterns.push(pat.to_string()));
+        let reference = Reference::new(&patterns);
+
+        // Build words from single letters and the letters of whole patterns,
+        // so that long patterns match, too. Some letters are uppercased.
+        let letters: Vec<String> = patterns
+            .iter()
+            .map(|pat| pat.replace(|c: char| c.is_ascii_digit() || c == '.', ""))
+            .filter(|letters| !letters.is_empty())
+            .collect();
+        let mut alphabet: Vec<char> = letters.iter().flat_map(|l| l.chars()).collect();
+        alphabet.sort();
+        alphabet.dedup();
+
+        let mut rng = Rng::seeded(tag);
+        for _ in 0..1000 {
+            let mut word = String::new();
+            for _ in 0..1 + rng.below(4) {
+                if rng.below(2) == 0 {
+                    word.push_str(&letters[rng.below(letters.len())]);
+                } else {
+                    word.push(alphabet[rng.below(alphabet.len())]);
+                }
+            }
+            if rng.below(4) == 0 {
+                word = word.chars().flat_map(char::to_uppercase).collect();
+            }
+
+            let explanation = hypher::hyphenate_explain(&word, lang);
+            let levels = reference.levels(&explanation.dotted);
+            assert!(
+                explanation.levels == levels,
+                "levels differ for {word:?} in {name}: {:?} != {levels:?}",
+                explanation.levels,
+            );
+        }
+    }
+}
+
+/// A straightforward implementation of Liang's algorithm to check the
+/// compressed tries against.
+struct Reference {
+    /// The letters of each pattern with the level before each letter and at
+    /// the end.
+    patterns: HashMap<String, Vec<u8>>,
+}
+
+impl Reference {
+    /// Collect the patterns. A later pattern with the same letters replaces an
+    /// earlier one.
+    fn new(patterns: &[String]) -> Self {
+        let mut map = HashMap::new();
+        for pat in patterns {
+            let mut letters = String::new();
+            let mut levels = vec![0];
+            for c in pat.chars() {
+                if let Some(level) = c.to_digit(10) {
+                    *levels.last_mut().unwrap() = level as u8;
+                } else {
+                    letters.push(c);
+                    levels.push(0);
+                }
+            }
+            map.insert(letters, levels);
+        }
+        Self { patterns: map }
+    }
+
+    /// Compute the level before each byte of a dotted word and at its end by
+    /// looking up every substring.
+    fn levels(&self, dotted: &str) -> Vec<u8> {
+        let mut bounds: Vec<usize> = dotted.char_indices().map(|(i, _)| i).collect();
+        bounds.push(dotted.len());
+
+        let mut levels = vec![0; dotted.len() + 1];
+        for (i, &start) in bounds.iter().enumerate() {
+            for &end in &bounds[i + 1..] {
+                if let Some(found) = self.patterns.get(&dotted[start..end]) {
+                    for (&offset, &level) in bounds[i..].iter().zip(found) {
+                        levels[offset] = levels[offset].max(level);
+                    }
+                }
+            }
+        }
+        levels
+    }
+}